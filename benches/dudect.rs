@@ -0,0 +1,95 @@
+//! Timing leakage test in the style of dudect [O.Reparaz J.Balasch I.Verbauwhede Dude, is my code
+//! constant time?, 2017]. Each operation is timed on two classes of secret inputs, a fixed value
+//! and random values, interleaved in random order; Welch's t-test then compares the two timing
+//! distributions, also after cropping the slowest measurements, which mostly come from interrupts.
+//! A statistic |t| above 4.5 means the timing depends on the secret. The leaky `Mod` and
+//! `MontgomeryMod` arithmetic are measured as controls: the test must flag them.
+//! Run with `cargo bench --bench dudect`; more samples make the test more sensitive.
+use std::{hint::black_box, time::Instant};
+use algebra::field::Field;
+use algebra::intmod::{Mod, PrimeField};
+use algebra::intmod::ct::{CtField, CtMod};
+use algebra::intmod::montgomery::{MontgomeryField, MontgomeryMod};
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::{One, Zero};
+use rand::Rng;
+
+const SAMPLES:usize=20_000;
+/// Operations timed together in one measurement, to stay well above the resolution of the clock.
+const REPEAT:usize=8;
+const THRESHOLD:f64=4.5;
+const PERCENTILES:[f64;5]=[0.5,0.75,0.9,0.95,0.99];
+
+/// Welford's online mean and variance.
+#[derive(Default)]
+struct Moments {
+    n:f64,
+    mean:f64,
+    m2:f64
+}
+impl Moments {
+    fn push(&mut self,x:f64){
+        self.n+=1.0;
+        let delta=x-self.mean;
+        self.mean+=delta/self.n;
+        self.m2+=delta*(x-self.mean);
+    }
+    fn variance(&self)->f64{
+        self.m2/(self.n-1.0)
+    }
+}
+fn welch_t(a:&Moments,b:&Moments)->f64{
+    (a.mean-b.mean)/(a.variance()/a.n+b.variance()/b.n).sqrt()
+}
+/// Times `operation` on inputs of class 0 (fixed) or 1 (random) chosen at random, and returns the
+/// largest |t| over the raw measurements and the measurements cropped at each percentile.
+fn leakage<T:Clone,U>(fixed:&T,random:impl Fn()->T,operation:impl Fn(&T)->U)->f64{
+    let mut rng=rand::thread_rng();
+    let classes:Vec<usize>=(0..SAMPLES).map(|_|rng.gen_range(0..2)).collect();
+    // both classes are read from the same array, so that they are equally likely to be in the cache
+    let inputs:Vec<T>=classes.iter().map(|&class|if class==0 {fixed.clone()} else {random()}).collect();
+    let mut times=Vec::with_capacity(SAMPLES);
+    for input in &inputs {
+        let start=Instant::now();
+        for _ in 0..REPEAT {black_box(operation(black_box(input)));}
+        times.push(start.elapsed().as_nanos() as f64);
+    }
+    let mut sorted=times.clone();
+    sorted.sort_by(|a,b|a.partial_cmp(b).unwrap());
+    let mut thresholds=vec![f64::INFINITY];
+    thresholds.extend(PERCENTILES.iter().map(|q|sorted[(q*SAMPLES as f64) as usize]));
+    thresholds.into_iter().map(|threshold|{
+        let mut moments=[Moments::default(),Moments::default()];
+        for (&class,&time) in classes.iter().zip(&times) {
+            if time<=threshold {moments[class].push(time);}
+        }
+        welch_t(&moments[0], &moments[1]).abs()
+    }).fold(0.0,f64::max)
+}
+fn report(name:&str,t:f64){
+    let verdict=if t>THRESHOLD {"timing depends on the secret"} else {"no leakage detected"};
+    println!("  {:<40} max |t| = {:>8.2}   {}",name,t,verdict);
+}
+fn main(){
+    let p:BigInt=(BigInt::one()<<255)-BigInt::from(19);
+    let field=PrimeField::try_new(p.clone()).unwrap();
+    let ct=CtField::<4>::from_prime_field(&field);
+    let montgomery=MontgomeryField::from_prime_field(&field);
+    let y=ct.random();
+    println!("p = 2^255-19, {} samples, fixed against random secret",SAMPLES);
+
+    println!("constant time");
+    report("CtMod product, secret x*y", leakage(&ct.zero(), ||ct.random(), |x|x*&y));
+    report("CtMod addition, secret x+y", leakage(&ct.zero(), ||ct.random(), |x|x+&y));
+    report("CtMod inverse, secret x", leakage(&ct.one(), ||ct.random(), |x|x.inverse()));
+    let secret_limbs=||->[u64;4] {rand::thread_rng().gen()};
+    report("CtMod power, secret exponent", leakage(&[1,0,0,0], secret_limbs, |e|y.pow_limbs(e)));
+    report("CtMod select, secret choice", leakage(&false, ||rand::thread_rng().gen(), |&c|CtMod::select(&y, &ct.one(), c)));
+
+    println!("controls");
+    let (mod_y,montgomery_y)=(y.to_mod(),MontgomeryMod::from_mod(&y.to_mod(),&montgomery));
+    let secret_exponent=||->BigInt {rand::thread_rng().gen_bigint_range(&BigInt::zero(), &p)};
+    report("Mod power, secret exponent", leakage(&BigInt::one(), secret_exponent, |e|mod_y.clone().pow_mod(e)));
+    report("MontgomeryMod product, secret x*y", leakage(&montgomery.zero(), ||MontgomeryMod::from_mod(&field.random(),&montgomery), |x|x*&montgomery_y));
+    report("Mod inverse, secret x", leakage(&field.one(), ||field.random(), |x:&Mod|x.inverse()));
+}
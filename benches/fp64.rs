@@ -0,0 +1,41 @@
+//! Compares polynomial arithmetic over Mod and over the word-sized field Fp64.
+//! Run with `cargo bench --bench fp64`.
+use std::time::{Duration, Instant};
+use algebra::intmod::PrimeField;
+use algebra::intmod::fp64::{Fp64Field, Fp64};
+use algebra::field::Field;
+use algebra::univariate::poly::Poly;
+use num_bigint::BigInt;
+
+const DEGREE:usize=200;
+const ITERATIONS:u32=5;
+
+fn time<T,F:FnMut()->T>(mut f:F)->(T,Duration){
+    let start=Instant::now();
+    let mut result=f();
+    for _ in 1..ITERATIONS {result=f();}
+    (result,start.elapsed()/ITERATIONS)
+}
+fn main(){
+    for p in [1_000_000_007u64,(1<<61)-1] {
+        let field=PrimeField::try_new(BigInt::from(p)).unwrap();
+        let small=Fp64Field::from_prime_field(&field).unwrap();
+        let f:Vec<_>=(0..=DEGREE).map(|_|field.random()).collect();
+        let g:Vec<_>=(0..DEGREE).map(|_|field.random()).collect();
+        let (f,g)=(Poly::new_from_coeffs(&f),Poly::new_from_coeffs(&g));
+        let to_small=|h:&Poly<_>|Poly::new_from_coeffs(&h.coeffs.iter().map(|c|Fp64::from_mod(c,&small)).collect::<Vec<_>>());
+        let (small_f,small_g)=(to_small(&f),to_small(&g));
+        println!("p = {}, degree {}",p,DEGREE);
+
+        let (product,mod_mul)=time(||&f*&g);
+        let (small_product,small_mul)=time(||&small_f*&small_g);
+        assert_eq!(to_small(&product),small_product);
+        println!("    {:<24}{:>12.1?}{:>12.1?}","mul (Mod, Fp64)",mod_mul,small_mul);
+
+        let (bezout,mod_gcd)=time(||Poly::gcdext(&f,&g));
+        let (small_bezout,small_gcd)=time(||Poly::gcdext(&small_f,&small_g));
+        assert_eq!(to_small(&bezout[2]),small_bezout[2]);
+        assert!(small_bezout[2].is_constant() && !small_bezout[2].coeffs[0].is_zero());
+        println!("    {:<24}{:>12.1?}{:>12.1?}","gcdext (Mod, Fp64)",mod_gcd,small_gcd);
+    }
+}
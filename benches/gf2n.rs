@@ -0,0 +1,30 @@
+//! Compares the bit-packed binary fields with GF(2^n) over PolyMod<Mod>.
+//! Run with `cargo bench --bench gf2n`.
+use std::time::{Duration, Instant};
+use algebra::field::Field;
+use algebra::univariate::gf2n::BinaryField;
+
+const ITERATIONS:u32=1000;
+
+fn time<T,F:FnMut()->T>(mut f:F,iterations:u32)->(T,Duration){
+    let start=Instant::now();
+    let mut result=f();
+    for _ in 1..iterations {result=f();}
+    (result,start.elapsed()/iterations)
+}
+fn main(){
+    for (name,field) in [("GF(2^8), AES",BinaryField::aes()),("GF(2^128), GCM",BinaryField::gcm())] {
+        let galois=field.galois_field();
+        let (x,y)=(field.random(),field.random());
+        let (gx,gy)=(galois.new(x.to_poly()),galois.new(y.to_poly()));
+        println!("{}",name);
+        let (product,packed)=time(||x.clone()*y.clone(),ITERATIONS);
+        let (galois_product,generic)=time(||gx.clone()*gy.clone(),10);
+        assert_eq!(product.to_poly(),galois_product.0.poly);
+        println!("    {:<28}{:>12.1?}{:>12.1?}","mul (packed, PolyMod)",packed,generic);
+        let (inverse,packed)=time(||x.inverse(),ITERATIONS/10);
+        let (galois_inverse,generic)=time(||gx.inverse(),10);
+        assert_eq!(inverse.to_poly(),galois_inverse.0.poly);
+        println!("    {:<28}{:>12.1?}{:>12.1?}","inverse (packed, PolyMod)",packed,generic);
+    }
+}
@@ -0,0 +1,47 @@
+//! Compares Mod with its Montgomery representation on products and exponentiations.
+//! Run with `cargo bench --bench montgomery`.
+use std::time::Instant;
+use algebra::intmod::{Mod, PrimeField};
+use algebra::intmod::montgomery::{MontgomeryField, MontgomeryMod};
+use num_bigint::BigInt;
+use num_traits::One;
+
+const PRODUCTS:u32=100_000;
+const POWERS:u32=100;
+
+fn main(){
+    let primes:[(&str,BigInt);3]=[
+        ("p = 2^127-1",(BigInt::one()<<127)-BigInt::one()),
+        ("p = 2^255-19",(BigInt::one()<<255)-BigInt::from(19)),
+        ("p = 2^521-1",(BigInt::one()<<521)-BigInt::one()),
+    ];
+    for (name,p) in primes {
+        let field=PrimeField::try_new(p.clone()).unwrap();
+        let montgomery=MontgomeryField::from_prime_field(&field);
+        let (x,y)=(field.random(),field.random());
+        let (mx,my)=(MontgomeryMod::from_mod(&x,&montgomery),MontgomeryMod::from_mod(&y,&montgomery));
+        println!("{}",name);
+
+        let start=Instant::now();
+        let mut z=x.clone();
+        for _ in 0..PRODUCTS {z=&z*&y;}
+        let mod_mul=start.elapsed()/PRODUCTS;
+        let start=Instant::now();
+        let mut mz=mx.clone();
+        for _ in 0..PRODUCTS {mz=&mz*&my;}
+        let montgomery_mul=start.elapsed()/PRODUCTS;
+        assert_eq!(mz.to_mod(),z);
+        println!("    {:<20}{:>12.1?}{:>12.1?}","mul (mod, mont)",mod_mul,montgomery_mul);
+
+        let start=Instant::now();
+        let mut power:Mod=x.clone();
+        for _ in 0..POWERS {power=x.clone().pow_mod(&y.n);}
+        let mod_pow=start.elapsed()/POWERS;
+        let start=Instant::now();
+        let mut montgomery_power=mx.clone();
+        for _ in 0..POWERS {montgomery_power=mx.pow(&y.n);}
+        let montgomery_pow=start.elapsed()/POWERS;
+        assert_eq!(montgomery_power.to_mod(),power);
+        println!("    {:<20}{:>12.1?}{:>12.1?}","pow (mod, mont)",mod_pow,montgomery_pow);
+    }
+}
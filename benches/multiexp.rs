@@ -0,0 +1,48 @@
+//! Compares batch inversion and multi-exponentiation with the naive loops over Mod.
+//! Run with `cargo bench --bench multiexp`.
+use std::time::Instant;
+use algebra::field::{Field, batch_inverse};
+use algebra::intmod::{Mod, PrimeField};
+use num_bigint::BigInt;
+use num_traits::One;
+
+const INVERSIONS:usize=1000;
+const RUNS:u32=5;
+
+fn main(){
+    let field=PrimeField::try_new((BigInt::one()<<255)-BigInt::from(19)).unwrap();
+    println!("p = 2^255-19");
+
+    let elements:Vec<Mod>=(0..INVERSIONS).map(|_|field.random()).collect();
+    let start=Instant::now();
+    let mut naive=Vec::new();
+    for _ in 0..RUNS {naive=elements.iter().map(|x|x.inverse()).collect();}
+    let naive_time=start.elapsed()/RUNS;
+    let start=Instant::now();
+    let mut batch=Vec::new();
+    for _ in 0..RUNS {batch=batch_inverse(&elements);}
+    let batch_time=start.elapsed()/RUNS;
+    assert_eq!(naive,batch);
+    println!("    {:<28}{:>12.1?}{:>12.1?}",format!("{} inverses (naive, batch)",INVERSIONS),naive_time,batch_time);
+
+    println!("    {:<28}{:>12}{:>12}{:>12}","k bases","naive","straus","pippenger");
+    for k in [2usize,4,16,64,256] {
+        let bases:Vec<Mod>=(0..k).map(|_|field.random()).collect();
+        let exps:Vec<BigInt>=(0..k).map(|_|field.random().n).collect();
+        let start=Instant::now();
+        let mut naive=field.new(BigInt::one());
+        for _ in 0..RUNS {naive=bases.iter().zip(&exps).fold(field.new(BigInt::one()),|acc,(g,e)|acc*g.clone().pow_mod(e));}
+        let naive_time=start.elapsed()/RUNS;
+        let start=Instant::now();
+        let mut straus=naive.clone();
+        for _ in 0..RUNS {straus=Mod::multi_pow_straus(&bases,&exps);}
+        let straus_time=start.elapsed()/RUNS;
+        let start=Instant::now();
+        let mut pippenger=naive.clone();
+        for _ in 0..RUNS {pippenger=Mod::multi_pow_pippenger(&bases,&exps);}
+        let pippenger_time=start.elapsed()/RUNS;
+        assert_eq!(straus,naive);
+        assert_eq!(pippenger,naive);
+        println!("    {:<28}{:>12.1?}{:>12.1?}{:>12.1?}",k,naive_time,straus_time,pippenger_time);
+    }
+}
@@ -0,0 +1,49 @@
+//! Compares the square root algorithms modulo primes of different shapes.
+//! Run with `cargo bench --bench sqrt`.
+use std::time::{Duration, Instant};
+use algebra::integers::IntUtilities;
+use algebra::intmod::{Mod, ModResult, PrimeField};
+use num_bigint::BigInt;
+use num_traits::One;
+
+const ITERATIONS:u32=20;
+
+fn time(name:&str,squares:&[Mod],sqrt:fn(&Mod)->ModResult){
+    let start=Instant::now();
+    for _ in 0..ITERATIONS {
+        for x in squares {
+            sqrt(x).unwrap();
+        }
+    }
+    let elapsed:Duration=start.elapsed()/(ITERATIONS*squares.len() as u32);
+    println!("    {:<16}{:>12.1?}",name,elapsed);
+}
+/// The first prime k*2^e+1 with the given number of bits.
+fn prime_with_two_adic_valuation(bits:u64,e:u64)->BigInt{
+    let step=BigInt::one()<<e;
+    let mut p=(BigInt::one()<<(bits-1))+BigInt::one();
+    while !BigInt::is_prime(&p) {p+=&step;}
+    p
+}
+fn main(){
+    let primes=[
+        ("p = 2^255-19, 5 mod 8",(BigInt::one()<<255)-BigInt::from(19)),
+        ("p = 2^255-765, 3 mod 4",(BigInt::one()<<255)-BigInt::from(765)),
+        ("p = 1 mod 2^8",prime_with_two_adic_valuation(256, 8)),
+        ("p = 1 mod 2^64",prime_with_two_adic_valuation(256, 64)),
+        ("p = 1 mod 2^128",prime_with_two_adic_valuation(256, 128)),
+    ];
+    for (name,p) in primes {
+        let field=PrimeField::try_new(p.clone()).unwrap();
+        let squares:Vec<Mod>=(0..10).map(|_|{let x=field.random(); &x*&x}).collect();
+        println!("{}",name);
+        time("dispatch",&squares,|x|x.clone().check_sqrt_mod_prime());
+        match u8::try_from(&p%8u8).unwrap() {
+            3|7=>time("3 mod 4",&squares,Mod::sqrt_3_mod_4),
+            5=>time("atkin",&squares,Mod::sqrt_atkin),
+            _=>{}
+        }
+        time("tonelli-shanks",&squares,Mod::sqrt_tonelli_shanks);
+        time("cipolla",&squares,Mod::sqrt_cipolla);
+    }
+}
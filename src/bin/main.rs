@@ -1,5 +1,7 @@
 
 use algebra::continued_functions::FiniteContinuedFunctions;
+use algebra::integers::siqs::siqs;
+use algebra::integers::IntUtilities;
 use termion::cursor::Goto;
 use termion::{self, color, style};
 use std::error::Error;
@@ -17,6 +19,22 @@ use num_rational::BigRational;
 
 
 fn main() {
+    let args:Vec<String>=std::env::args().collect();
+    if args.len()==3 && args[1]=="siqs" {
+        /* cargo run --release -- siqs <n> */
+        let n=args[2].parse::<BigInt>().expect("Invalid integer");
+        if BigInt::is_prime(&n) {
+            println!("{} is prime",n);
+            return;
+        }
+        let start=std::time::Instant::now();
+        match siqs(&n) {
+            Some(d)=>println!("{} = {} * {}",n,d,&n/&d),
+            None=>println!("No factor found")
+        }
+        println!("elapsed {:?}",start.elapsed());
+        return;
+    }
     let f=BigRational::new(BigInt::from(120),BigInt::from(49));
     let cf=FiniteContinuedFunctions::from_rational(f);
     println!("{}",cf);
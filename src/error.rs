@@ -0,0 +1,62 @@
+//! The error type of the crate.
+//! Operators such as `+` or `/` panic on invalid input, as the arithmetic of the standard library does;
+//! each of them has a fallible counterpart (`checked_add`, `try_inverse`, `try_div_rem`, ...) that
+//! returns an [`AlgebraError`] instead, for callers that need to recover.
+//!
+//! # Example
+//! ```
+//! use algebra::error::AlgebraError;
+//! use algebra::intmod::PrimeField;
+//! use algebra::field::Field;
+//! use num_bigint::BigInt;
+//! let x=PrimeField(BigInt::from(13)).new(BigInt::from(2));
+//! let y=PrimeField(BigInt::from(17)).new(BigInt::from(2));
+//! assert_eq!(x.checked_add(&y),Err(AlgebraError::ModulusMismatch));
+//! assert_eq!(x.zero().try_inverse(),Err(AlgebraError::DivisionByZero));
+//! ```
+use std::fmt;
+use num_bigint::BigInt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlgebraError {
+    /// The element is not a square modulo the prime p.
+    QuadraticNonResidueModP,
+    /// The modulus is not a prime.
+    NonPrimeModulus(BigInt),
+    /// The modulus does not fit a fixed-size representation.
+    ModulusTooLarge(BigInt),
+    /// The integer is not invertible modulo n, carrying its gcd with n.
+    NonInvertibleElement(BigInt),
+    /// The polynomial defining an extension field is reducible.
+    ReducibleModulus,
+    /// The polynomial is not coprime to the modulus.
+    NonInvertiblePolynomial,
+    /// The operands live in rings with different moduli.
+    ModulusMismatch,
+    /// Division by zero, or inversion of zero in a field.
+    DivisionByZero,
+    /// The monomial is not divisible by the divisor.
+    NotDivisible,
+    /// Subtracting the multi-indices would give a negative exponent.
+    NegativeExponent,
+    /// Only terms with the same monomial can be added.
+    DifferentMonomials
+}
+impl fmt::Display for AlgebraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlgebraError::QuadraticNonResidueModP=>write!(f, "Not a quadratic residue modulo p!"),
+            AlgebraError::NonPrimeModulus(n)=>write!(f, "{} is not a prime modulus!",n),
+            AlgebraError::ModulusTooLarge(n)=>write!(f, "The modulus {} is too large for this representation!",n),
+            AlgebraError::NonInvertibleElement(d)=>write!(f, "Not an invertible element! Its gcd with the modulus is {}",d),
+            AlgebraError::ReducibleModulus=>write!(f, "The modulus is reducible!"),
+            AlgebraError::NonInvertiblePolynomial=>write!(f, "Not an invertible element! The polynomial is not coprime to the modulus"),
+            AlgebraError::ModulusMismatch=>write!(f, "Elements of different rings!"),
+            AlgebraError::DivisionByZero=>write!(f, "Division by zero!"),
+            AlgebraError::NotDivisible=>write!(f, "Cannot divide! The monomial is not divisible by the divisor"),
+            AlgebraError::NegativeExponent=>write!(f, "Cannot subtract! An exponent would be negative"),
+            AlgebraError::DifferentMonomials=>write!(f, "Cannot add terms with different monomials!")
+        }
+    }
+}
+impl std::error::Error for AlgebraError {}
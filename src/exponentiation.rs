@@ -0,0 +1,165 @@
+//! Exponentiation in any [`Ring`], for non-negative exponents.
+//! - [`pow_sliding_window`] is the general purpose method, with a window size chosen from the
+//!   length of the exponent.
+//! - [`FixedBase`] precomputes a table of powers of a base that is raised to many exponents,
+//!   e.g. a generator, after which each exponentiation costs no squarings.
+//! - [`pow_ladder`] is the Montgomery ladder: the sequence of ring operations depends only on the
+//!   number of bits processed, not on the exponent. It is constant time only when the ring
+//!   arithmetic is, which is not the case for `BigInt`-based elements.
+//!
+//! Inversion is not available in a ring, so negative exponents are left to the callers:
+//! [`Mod::pow_mod`](crate::intmod::Mod::pow_mod) and [`PolyMod::pow`](crate::univariate::polymod::PolyMod::pow)
+//! invert the base first.
+//!
+//! # Example
+//! ```
+//! #[macro_use] extern crate algebra;
+//! use algebra::exponentiation::{pow_sliding_window, pow_ladder, FixedBase};
+//! use num_bigint::BigInt;
+//! use algebra::intmod::modn::IntegerModRing;
+//! // integers are a ring, and so are polynomials over them
+//! assert_eq!(pow_sliding_window(&BigInt::from(3),&BigInt::from(40)),BigInt::from(3).pow(40u32));
+//! let f=poly!(BigInt::from(1),BigInt::from(1));
+//! assert_eq!(pow_ladder(&f,&BigInt::from(3),2).to_string(),"1*x^3+3*x^2+3*x+1");
+//! // and so is Z/nZ: textbook RSA with n = 61*53
+//! let zn=IntegerModRing(BigInt::from(3233));
+//! let table=FixedBase::new(&zn.new(BigInt::from(65)),12,4);
+//! assert_eq!(table.pow(&BigInt::from(17)),zn.new(BigInt::from(2790)));
+//! ```
+use num_bigint::BigInt;
+use num_traits::Signed;
+use crate::ring::Ring;
+
+/// Window size for exponents of the given length: the precomputation of 2^(w-1) odd powers
+/// pays off from these thresholds on.
+fn window_size(bits:u64)->u64{
+    match bits {
+        0..=23=>1,
+        24..=79=>3,
+        80..=239=>4,
+        240..=671=>5,
+        _=>6
+    }
+}
+fn check_exponent(exp:&BigInt){
+    if exp.is_negative() {panic!("Negative exponent {} in a ring",exp)}
+}
+/// Left-to-right sliding-window exponentiation [Algorithm 14.85 A.Menezes P.van Oorschot S.Vanstone
+/// Handbook of applied cryptography]: the odd powers g, g^3, ..., g^(2^w-1) are precomputed, then each
+/// window of at most w bits ending with a one costs a single product.
+///
+/// # Example
+/// ```
+/// use algebra::exponentiation::pow_sliding_window;
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z101=PrimeField(BigInt::from(101));
+/// assert_eq!(pow_sliding_window(&z101.new(BigInt::from(2)),&BigInt::from(100)),z101.new(BigInt::from(1)));
+/// ```
+pub fn pow_sliding_window<R:Ring>(base:&R,exp:&BigInt)->R{
+    check_exponent(exp);
+    let bits=exp.bits();
+    let w=window_size(bits);
+    // odd_powers[i] = base^(2i+1)
+    let square=base.clone()*base;
+    let mut odd_powers=vec![base.clone()];
+    for i in 1..1usize<<(w-1) {
+        odd_powers.push(odd_powers[i-1].clone()*&square);
+    }
+    let mut result=base.one();
+    let mut i=bits as i64-1;
+    while i>=0 {
+        if !exp.bit(i as u64) {
+            result=result.clone()*&result;
+            i-=1;
+            continue;
+        }
+        // longest window exp[i..=j] of at most w bits ending with a one
+        let mut j=(i-w as i64+1).max(0);
+        while !exp.bit(j as u64) {j+=1;}
+        let mut digit=0usize;
+        for k in (j..=i).rev() {
+            result=result.clone()*&result;
+            digit=digit<<1|exp.bit(k as u64) as usize;
+        }
+        result=result*&odd_powers[digit>>1];
+        i=j-1;
+    }
+    result
+}
+/// Montgomery ladder: processes exactly `bits` bits of the exponent with one product and one squaring
+/// each, keeping the invariant r1 = r0*base [M.Joye S.Yen The Montgomery powering ladder, 2002].
+/// It panics if the exponent does not fit in `bits` bits.
+pub fn pow_ladder<R:Ring>(base:&R,exp:&BigInt,bits:u64)->R{
+    check_exponent(exp);
+    if exp.bits()>bits {panic!("The exponent {} does not fit in {} bits",exp,bits)}
+    let mut r0=base.one();
+    let mut r1=base.clone();
+    for i in (0..bits).rev() {
+        if exp.bit(i) {
+            r0=r0*&r1;
+            r1=r1.clone()*&r1;
+        } else {
+            r1=r1*&r0;
+            r0=r0.clone()*&r0;
+        }
+    }
+    r0
+}
+/// Fixed-base windowing with a precomputed table [section 14.6.3 A.Menezes P.van Oorschot S.Vanstone
+/// Handbook of applied cryptography]: for a window of w bits the table holds g^(d*2^(wi)) for every digit
+/// d < 2^w and every window i, so g^e is the product of one entry per non-zero digit of e.
+/// Exponents longer than the table fall back to [`pow_sliding_window`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedBase<R> {
+    base:R,
+    window:u64,
+    max_bits:u64,
+    table:Vec<Vec<R>>
+}
+impl<R:Ring> FixedBase<R> {
+/// Precomputes the table for exponents of up to max_bits bits, with windows of the given size.
+///
+/// # Example
+/// ```
+/// use algebra::exponentiation::{FixedBase, pow_sliding_window};
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z1019=PrimeField::try_new(BigInt::from(1019)).unwrap();
+/// let generator=z1019.new(BigInt::from(2));
+/// let table=FixedBase::new(&generator,10,3);
+/// for e in [0,1,509,1018] {
+///     assert_eq!(table.pow(&BigInt::from(e)),pow_sliding_window(&generator,&BigInt::from(e)));
+/// }
+/// ```
+    pub fn new(base:&R,max_bits:u64,window:u64)->FixedBase<R>{
+        if window==0 || window>16 {panic!("The window must have between 1 and 16 bits, found {}",window)}
+        let mut table=Vec::new();
+        // g^(2^(wi)) for the current window i
+        let mut power=base.clone();
+        for _ in 0..max_bits.div_ceil(window) {
+            let mut row=vec![base.one(),power.clone()];
+            for d in 2..1usize<<window {
+                row.push(row[d-1].clone()*&power);
+            }
+            power=row[(1<<window)-1].clone()*&power;
+            table.push(row);
+        }
+        FixedBase { base: base.clone(), window, max_bits, table }
+    }
+    pub fn base(&self)->&R{
+        &self.base
+    }
+/// Computes base^exp.
+    pub fn pow(&self,exp:&BigInt)->R{
+        check_exponent(exp);
+        if exp.bits()>self.max_bits {return pow_sliding_window(&self.base, exp);}
+        let mut result=self.base.one();
+        for (i,row) in self.table.iter().enumerate() {
+            let start=i as u64*self.window;
+            let digit=(0..self.window).fold(0usize,|d,j|if exp.bit(start+j) {d|1<<j} else {d});
+            if digit!=0 {result=result*&row[digit];}
+        }
+        result
+    }
+}
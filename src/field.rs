@@ -1,4 +1,7 @@
 use std::{ops::{Add, Div, Mul, Neg, Sub},fmt::{Display, Debug}};
+use crate::error::AlgebraError;
+/// A commutative field. Every field is also a [`EuclideanDomain`](crate::ring::EuclideanDomain),
+/// and so a [`Ring`](crate::ring::Ring), through the blanket implementations of [`crate::ring`].
 pub trait Field
 where
     Self:Sized
@@ -26,4 +29,51 @@ where
     fn is_zero(&self)->bool;
     fn is_one(&self)->bool;
     fn inverse(&self)->Self;
+/// Inverse of a non-zero element, or `AlgebraError::DivisionByZero`.
+    fn try_inverse(&self)->Result<Self,AlgebraError>{
+        if self.is_zero() {Err(AlgebraError::DivisionByZero)} else {Ok(self.inverse())}
+    }
+}
+/// Inverts every element of a slice with a single field inversion and 3(n-1) multiplications,
+/// by Montgomery's trick [P.Montgomery Speeding the Pollard and elliptic curve methods of factorization, 1987]:
+/// the prefix products x1...xi are inverted all at once through the inverse of x1...xn.
+/// It panics if some element is not invertible, see [`try_batch_inverse`].
+///
+/// # Example
+/// ```
+/// use algebra::field::{Field,batch_inverse};
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z13=PrimeField(BigInt::from(13));
+/// let elements:Vec<_>=(1..13).map(|n|z13.new(BigInt::from(n))).collect();
+/// let inverses=batch_inverse(&elements);
+/// for (x,y) in elements.iter().zip(&inverses) {
+///     assert!((x*y).is_one());
+/// }
+/// ```
+pub fn batch_inverse<F:Field>(elements:&[F])->Vec<F>{
+    try_batch_inverse(elements).unwrap_or_else(|error|panic!("{}",error))
+}
+/// Fallible [`batch_inverse`]: when the product is not invertible, returns the error of the first
+/// element that is not invertible.
+pub fn try_batch_inverse<F:Field>(elements:&[F])->Result<Vec<F>,AlgebraError>{
+    let Some(first)=elements.first() else {return Ok(Vec::new())};
+    let mut prefix=Vec::with_capacity(elements.len());
+    let mut product=first.one();
+    for x in elements {
+        product=product*x;
+        prefix.push(product.clone());
+    }
+    let mut inverse=match product.try_inverse() {
+        Ok(inverse)=>inverse,
+        Err(error)=>return Err(elements.iter().find_map(|x|x.try_inverse().err()).unwrap_or(error))
+    };
+    let mut inverses=vec![first.zero();elements.len()];
+    for i in (1..elements.len()).rev() {
+        // inverse is (x[0]...x[i])^-1 at this point
+        inverses[i]=inverse.clone()*&prefix[i-1];
+        inverse=inverse*&elements[i];
+    }
+    inverses[0]=inverse;
+    Ok(inverses)
 }
@@ -1,9 +1,46 @@
+pub mod factor;
+pub mod siqs;
+pub mod primes;
+
 use std::ops::BitAnd;
 use num_bigint::{BigInt, RandBigInt};
-use num_bigint::Sign::{Minus,Plus};
+use num_bigint::Sign::Minus;
 use num_traits::{Zero,One, Signed, ToPrimitive};
 impl IntUtilities for BigInt {}
 
+/// The value of a quadratic character, such as the Legendre symbol (a/p): whether a non-zero element
+/// is a square, or zero.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum QuadraticCharacter {
+    Zero,
+    Residue,
+    NonResidue
+}
+impl QuadraticCharacter {
+/// The character of the symbol 0, 1 or -1. It panics on any other value.
+    pub fn from_symbol(symbol:i8)->QuadraticCharacter{
+        match symbol {
+            0=>QuadraticCharacter::Zero,
+            1=>QuadraticCharacter::Residue,
+            -1=>QuadraticCharacter::NonResidue,
+            _=>panic!("{} is not a value of a quadratic character",symbol)
+        }
+    }
+    pub fn is_residue(&self)->bool{
+        *self==QuadraticCharacter::Residue
+    }
+}
+/// The symbol 0, 1 or -1.
+impl From<QuadraticCharacter> for i8 {
+    fn from(character:QuadraticCharacter)->Self{
+        match character {
+            QuadraticCharacter::Zero=>0,
+            QuadraticCharacter::Residue=>1,
+            QuadraticCharacter::NonResidue=>-1
+        }
+    }
+}
+
 pub trait IntUtilities{
     fn random_8bit()->BigInt{
         let mut rng = rand::thread_rng();
@@ -44,59 +81,20 @@ fn compute_power_of_two(mut a:BigInt,mut b:BigInt)->(BigInt,BigInt,u64){
     b>>=&shift;
     (a,b,shift)
 }
-/// This is a binary algorithm that compute the greates common divisors.
-/// The binary algorithm is especially well suited for computing GCD of multiprecision numbers.
-/// This is because no division is performed: all division by 2 in this algorithm are performed using shift!
+/// Greatest common divisor, non-negative. It is [`crate::ring::gcd`] on the integers.
 /// 
 /// # Example
 /// ```
 /// use crate::algebra::integers::IntUtilities;
 /// use num_bigint::BigInt;
 /// let a=BigInt::from(60u8);
-/// let b=BigInt::from(24u8);
+/// let b=BigInt::from(-24);
 /// let expected_value=BigInt::from(12u8);
 /// assert_eq!(BigInt::gcd(a,b),expected_value);
 /// ```
 /// 
-fn gcd(mut a:BigInt, mut b:BigInt)->BigInt{
-    if a.sign()==Minus {a=-a}
-    if b.sign()==Minus{b=-b}
-    let k:u64;
-    if a<b {
-        std::mem::swap(&mut a,&mut b);
-    }
-    if b==BigInt::zero() {return a} else {
-        let r=&a%&b;
-        a=b;
-        b=r;
-    }
-    if b==BigInt::zero(){return a} else {
-        let z=Self::compute_power_of_two(a, b);
-        a=z.0;
-        b=z.1;
-        k=z.2;
-        }
-    if BigInt::is_even(&a) {
-        let shift=a.trailing_zeros().unwrap();
-        a>>=shift;
-    } else if BigInt::is_even(&b) {
-            let shift=b.trailing_zeros().unwrap();
-            b>>=shift;
-        }
-    loop{
-    let mut t:BigInt=(&a-&b)>>1;
-    if t==BigInt::zero() {
-        let exp=(1u64<<k).to_be_bytes();
-        return BigInt::from_signed_bytes_be(&exp)*a;
-        }
-        while BigInt::is_even(&t) {
-            t=t/BigInt::from(2u8);
-        };
-        if t.sign()==Plus{
-            a=t;
-            } else {b=-t}
-
-    }
+fn gcd(a:BigInt, b:BigInt)->BigInt{
+    crate::ring::gcd(&a, &b)
 }
 /// # Example
 /// ```
@@ -109,9 +107,8 @@ fn gcd(mut a:BigInt, mut b:BigInt)->BigInt{
 /// ```
 fn lcm(a:BigInt,b:BigInt)->BigInt{
     &a*&b/BigInt::gcd(a, b)}
-/// This is the euclidean extended binary algorithm that compute the greates common divisors.
-/// given two integers a and b, the algorithms given below not only compute d = gcd(a, b)
-///  but also the integers u and v such that au + bv = d.
+/// Extended gcd: given two integers a and b, returns [u,v,d] with d = gcd(a, b) >= 0 and
+/// au + bv = d. It is [`crate::ring::gcdext`] on the integers.
 /// 
 /// # Example
 /// ```
@@ -121,90 +118,32 @@ fn lcm(a:BigInt,b:BigInt)->BigInt{
 /// let b=BigInt::from(24u8);
 /// let z=BigInt::gcdext(a,b);
 /// 
-/// assert_eq!(z,[BigInt::from(-3),BigInt::from(8),BigInt::from(12)]);
+/// assert_eq!(z,[BigInt::from(1),BigInt::from(-2),BigInt::from(12)]);
 /// ```
 /// 
-fn gcdext(mut a:BigInt,mut b:BigInt)->[BigInt;3]{
-    let mut sign_a:BigInt=BigInt::one();
-    let mut sign_b:BigInt=BigInt::one();
-    if a.sign()==Minus {a=-a; sign_a=BigInt::from(-1)};
-    if b.sign()==Minus{b=-b; sign_b=BigInt::from(-1)};
-
-    let f1:bool;
-    if a>b {
-        std::mem::swap(&mut a, &mut b);
-        f1=true;
-    } else {f1=false;}
-
-    let q=&b/&a;
-    let r=&b%&a;
-    let mut u=BigInt::zero();
-    let mut v:BigInt;
-    let d:BigInt;
-    b=a;
-    a=r;
-    if a==BigInt::zero(){
-        v=BigInt::one();
-        d=b;
-        if f1{return [u,v,d];} else {return [v,u,d];}
-    }
-    let mut k=0u64;
-    let mut f= false;
-    while BigInt::is_even(&b) && BigInt::is_even(&a) {
-        let z =BigInt::compute_power_of_two(a, b);
-        a=z.0;
-        b=z.1;
-        k=z.2;
-    }
-    if BigInt::is_even(&a){
-        std::mem::swap(&mut a, &mut b);
-        f=true;}
-    
-    let mut u_b=BigInt::one();
-    let mut aux_a=b.clone();
-    let mut aux_b=a.clone();
-    let mut aux_v=a.clone();
-    let mut u_a:BigInt;
-    let mut aux_t:BigInt;
-    
-    if BigInt::is_even(&b)==false{
-        u_a=BigInt::zero(); aux_t=-&a;
-    } else {
-        u_a=(BigInt::one()+&a)/2;
-        aux_t=&b/BigInt::from(2);}
-    
-    while aux_t !=BigInt::zero() {
-
-        if aux_t>BigInt::zero() {
-            u_b=u_a;aux_a=aux_t;} else {
-            aux_b=&a-&u_a;aux_v=-&aux_t;
-        }
-
-        u_a=&u_b-&aux_b;
-        aux_t=&aux_a-&aux_v;
-
-        if u_a<BigInt::zero(){
-            u_a=&u_a+&a;}
-        while BigInt::is_even(&aux_t) && aux_t != BigInt::zero() {
-            aux_t=&aux_t/2;
-            if BigInt::is_even(&u_a) {u_a=&u_a/2;} else {u_a=(&u_a+&a)/BigInt::from(2);}      
-        }
-
-        }
-    u=u_b.clone();
-    let v_exp=(BigInt::one()<<k).to_bytes_be();
-    d=BigInt::from_signed_bytes_be(&v_exp.1)*&aux_a;   
-    v=(&aux_a-&b*&u_b)/&a; 
-    if f {std::mem::swap(&mut u, &mut v);}
-    u=u-&v*&q; 
-    if f1 {return [sign_b*v,sign_a*u,d];} else {return [sign_a*u,sign_b*v,d];}  
+fn gcdext(a:BigInt,b:BigInt)->[BigInt;3]{
+    crate::ring::gcdext(&a, &b)
 }
 fn check_gcdext( a:BigInt,b:BigInt){
 let v=BigInt::gcdext(a.clone(),b.clone());
 assert_eq!(a*&v[0]+b*&v[1],v[2])
 }
 const TAB2:[i8;8]=[0,1,0,-1,0,-1,0,1];
-fn kroneker(mut a:BigInt,mut b:BigInt)->i8 {
+/// Kronecker symbol (a/b), defined for all integers a and b [Algorithm 1.4.10 H.Cohen A course in
+/// computational number theory]. It extends the Jacobi symbol to even and negative b, and it is
+/// zero exactly when gcd(a,b)!=1.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// assert_eq!(BigInt::kronecker(&BigInt::from(5),&BigInt::from(8)),-1);
+/// assert_eq!(BigInt::kronecker(&BigInt::from(-1),&BigInt::from(7)),-1);
+/// assert_eq!(BigInt::kronecker(&BigInt::from(-1),&BigInt::from(-7)),1);
+/// assert_eq!(BigInt::kronecker(&BigInt::from(6),&BigInt::from(4)),0);
+/// ```
+fn kronecker(a:&BigInt,b:&BigInt)->i8 {
+    let (mut a,mut b)=(a.clone(),b.clone());
     let mut k:i8;
     if b.is_zero(){
         if !a.abs().is_one() {return 0i8;} else { return 1i8;}
@@ -248,4 +187,362 @@ fn kroneker(mut a:BigInt,mut b:BigInt)->i8 {
 
     }
 }
+/// Same as [`IntUtilities::kronecker`], taking its arguments by value.
+#[deprecated(note = "use `kronecker`")]
+fn kroneker(a:BigInt,b:BigInt)->i8 {
+    BigInt::kronecker(&a, &b)
+}
+/// Jacobi symbol (a/n) for an odd positive n, the product of the Legendre symbols (a/p) over the
+/// primes p dividing n, with multiplicity. A symbol equal to 1 does not imply that a is a square
+/// modulo a composite n. It panics if n is even or not positive.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// // 2 is not a square modulo 15, but (2/3)(2/5) = (-1)(-1) = 1
+/// assert_eq!(BigInt::jacobi(&BigInt::from(2),&BigInt::from(15)),1);
+/// assert_eq!(BigInt::jacobi(&BigInt::from(7),&BigInt::from(15)),-1);
+/// ```
+fn jacobi(a:&BigInt,n:&BigInt)->i8 {
+    if BigInt::is_even(n) || !n.is_positive() {panic!("The Jacobi symbol needs an odd positive modulus, found {}",n)}
+    BigInt::kronecker(a, n)
+}
+/// Legendre symbol (a/p) for an odd prime p, as a [`QuadraticCharacter`]. The primality of p is not
+/// checked: for an odd composite p the result is the Jacobi symbol. It panics if p is even or not
+/// positive.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::{IntUtilities, QuadraticCharacter};
+/// use num_bigint::BigInt;
+/// let p=BigInt::from(23);
+/// assert_eq!(BigInt::legendre(&BigInt::from(2),&p),QuadraticCharacter::Residue);
+/// assert_eq!(BigInt::legendre(&BigInt::from(-1),&p),QuadraticCharacter::NonResidue);
+/// assert_eq!(BigInt::legendre(&BigInt::from(46),&p),QuadraticCharacter::Zero);
+/// ```
+fn legendre(a:&BigInt,p:&BigInt)->QuadraticCharacter {
+    QuadraticCharacter::from_symbol(BigInt::jacobi(a, p))
+}
+const SMALL_PRIMES:[u32;25]=[2,3,5,7,11,13,17,19,23,29,31,37,41,43,47,53,59,61,67,71,73,79,83,89,97];
+/// Bases for which the strong pseudoprime test is known to be deterministic
+/// for every n < 3.18*10^23, hence for every n < 2^64 [J.Sorenson J.Webster Strong pseudoprimes
+/// to twelve prime bases, 2017].
+const DETERMINISTIC_WITNESSES:[u32;12]=[2,3,5,7,11,13,17,19,23,29,31,37];
+/// Strong probable prime test (one round of Miller-Rabin) [Algorithm 8.2.2 H.Cohen A course in computational number theory].
+/// n must be odd and greater than 3, base is reduced modulo n.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// // 2047=23*89 is the smallest strong pseudoprime to base 2
+/// assert!(BigInt::is_strong_probable_prime(&BigInt::from(2047),&BigInt::from(2)));
+/// assert!(!BigInt::is_strong_probable_prime(&BigInt::from(2047),&BigInt::from(3)));
+/// ```
+fn is_strong_probable_prime(n:&BigInt,base:&BigInt)->bool{
+    let n_minus_one=n-BigInt::one();
+    let a=base%n;
+    if a.is_zero()||a.is_one()||a==n_minus_one {return true;}
+    let s=n_minus_one.trailing_zeros().unwrap();
+    let q=&n_minus_one>>s;
+    let mut b=a.modpow(&q,n);
+    if b.is_one()||b==n_minus_one {return true;}
+    for _ in 1..s {
+        b=&b*&b%n;
+        if b==n_minus_one {return true;}
+        if b.is_one() {return false;}
+    }
+    false
+}
+/// Miller-Rabin probabilistic primality test with `rounds` random bases.
+/// A composite number passes with probability at most 4^(-rounds).
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// assert!(BigInt::miller_rabin(&BigInt::from(1_000_000_007u64),20));
+/// assert!(!BigInt::miller_rabin(&BigInt::from(561),20));
+/// ```
+fn miller_rabin(n:&BigInt,rounds:usize)->bool{
+    if let Some(small)=BigInt::trial_division_check(n) {return small;}
+    let mut rng=rand::thread_rng();
+    let upper=n-BigInt::one();
+    for _ in 0..rounds {
+        let base=rng.gen_bigint_range(&BigInt::from(2),&upper);
+        if !BigInt::is_strong_probable_prime(n, &base){return false;}
+    }
+    true
+}
+/// Strong Lucas probable prime test with the parameters (P,Q)=(1,(1-D)/4) of Selfridge's method A,
+/// where D is the first of 5,-7,9,-11,... with Kronecker symbol (D/n)=-1.
+/// n must be odd and greater than 3.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// // 5459=53*103 is the smallest strong Lucas pseudoprime
+/// assert!(BigInt::is_strong_lucas_probable_prime(&BigInt::from(5459)));
+/// assert!(!BigInt::is_strong_probable_prime(&BigInt::from(5459),&BigInt::from(2)));
+/// ```
+fn is_strong_lucas_probable_prime(n:&BigInt)->bool{
+    if BigInt::is_square(n) {return false;}
+    let mut d=BigInt::from(5);
+    loop {
+        let k=BigInt::kronecker(&BigInt::modulo(&d, n), n);
+        if k==-1 {break;}
+        if k==0 && d.abs()!=*n {return false;}
+        d= if d.sign()==Minus {-d+BigInt::from(2)} else {-d-BigInt::from(2)};
+    }
+    let p=BigInt::one();
+    let q=BigInt::modulo(&((BigInt::one()-&d)/BigInt::from(4)), n);
+    let d=BigInt::modulo(&d, n);
+    let n_plus_one=n+BigInt::one();
+    let s=n_plus_one.trailing_zeros().unwrap();
+    let k=&n_plus_one>>s;
+    let half=|x:BigInt|->BigInt{ if BigInt::is_even(&x) {x>>1} else {(x+n)>>1} };
+    let mut u=BigInt::one();
+    let mut v=p.clone();
+    let mut q_k=q.clone();
+    let bits=BigInt::to_binary(&k);
+    for bit in bits.iter().skip(1) {
+        u=&u*&v%n;
+        v=BigInt::modulo(&(&v*&v-&q_k*BigInt::from(2)), n);
+        q_k=&q_k*&q_k%n;
+        if bit.is_one() {
+            let new_u=half((&p*&u+&v)%n);
+            v=half((&d*&u+&p*&v)%n);
+            u=new_u;
+            q_k=&q_k*&q%n;
+        }
+    }
+    if u.is_zero()||v.is_zero() {return true;}
+    for _ in 1..s {
+        v=BigInt::modulo(&(&v*&v-&q_k*BigInt::from(2)), n);
+        if v.is_zero() {return true;}
+        q_k=&q_k*&q_k%n;
+    }
+    false
+}
+/// Baillie-PSW primality test: a strong probable prime test to base 2 followed by
+/// a strong Lucas probable prime test. No composite passing both is known.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// let mersenne=(BigInt::from(1)<<127)-BigInt::from(1);
+/// assert!(BigInt::baillie_psw(&mersenne));
+/// assert!(!BigInt::baillie_psw(&(&mersenne*BigInt::from(3))));
+/// ```
+fn baillie_psw(n:&BigInt)->bool{
+    if let Some(small)=BigInt::trial_division_check(n) {return small;}
+    BigInt::is_strong_probable_prime(n, &BigInt::from(2))&&BigInt::is_strong_lucas_probable_prime(n)
+}
+/// Primality test. For n < 2^64 the answer is proven, using the strong
+/// pseudoprime test with the first twelve primes as witnesses. Larger numbers
+/// are tested with Baillie-PSW.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// assert!(BigInt::is_prime(&BigInt::from(18446744073709551557u64)));
+/// assert!(!BigInt::is_prime(&BigInt::from(3215031751u64)));
+/// assert!(!BigInt::is_prime(&BigInt::from(-7)));
+/// ```
+fn is_prime(n:&BigInt)->bool{
+    if let Some(small)=BigInt::trial_division_check(n) {return small;}
+    if n.bits()<=64 {
+        return Self::DETERMINISTIC_WITNESSES.iter()
+            .all(|w|BigInt::is_strong_probable_prime(n, &BigInt::from(*w)));
+    }
+    BigInt::baillie_psw(n)
+}
+/// Trial division by the primes below 100. Returns `Some(answer)` when this
+/// already decides primality of n, `None` otherwise.
+fn trial_division_check(n:&BigInt)->Option<bool>{
+    if n<=&BigInt::one() {return Some(false);}
+    for p in Self::SMALL_PRIMES {
+        let p=BigInt::from(p);
+        if *n==p {return Some(true);}
+        if (n%&p).is_zero() {return Some(false);}
+    }
+    if n<&BigInt::from(97*97) {return Some(true);}
+    None
+}
+/// Smallest prime greater than n.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// assert_eq!(BigInt::next_prime(&BigInt::from(89)),BigInt::from(97));
+/// assert_eq!(BigInt::next_prime(&BigInt::from(-5)),BigInt::from(2));
+/// ```
+fn next_prime(n:&BigInt)->BigInt{
+    if n<&BigInt::from(2) {return BigInt::from(2);}
+    let mut candidate=n+BigInt::one();
+    if BigInt::is_even(&candidate) && candidate!=BigInt::from(2) {candidate+=1;}
+    while !BigInt::is_prime(&candidate) {candidate+=2;}
+    candidate
+}
+/// Largest prime smaller than n, None if n<=2.
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// assert_eq!(BigInt::prev_prime(&BigInt::from(97)),Some(BigInt::from(89)));
+/// assert_eq!(BigInt::prev_prime(&BigInt::from(2)),None);
+/// ```
+fn prev_prime(n:&BigInt)->Option<BigInt>{
+    if n<=&BigInt::from(2) {return None;}
+    if n==&BigInt::from(3) {return Some(BigInt::from(2));}
+    let mut candidate=n-BigInt::one();
+    if BigInt::is_even(&candidate) {candidate-=1;}
+    while !BigInt::is_prime(&candidate) {candidate-=2;}
+    Some(candidate)
+}
+/// If n=m^k with m>1 and k>1 maximal, returns (m,k).
+///
+/// # Example
+/// ```
+/// use crate::algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// assert_eq!(BigInt::perfect_power(&BigInt::from(1024)),Some((BigInt::from(2),10)));
+/// assert_eq!(BigInt::perfect_power(&BigInt::from(36)),Some((BigInt::from(6),2)));
+/// assert_eq!(BigInt::perfect_power(&BigInt::from(72)),None);
+/// ```
+fn perfect_power(n:&BigInt)->Option<(BigInt,u32)>{
+    if n<=&BigInt::one() {return None;}
+    for k in (2..=n.bits() as u32).rev() {
+        let root=n.nth_root(k);
+        if root>BigInt::one() && root.pow(k)==*n {return Some((root,k));}
+    }
+    None
+}
+fn is_perfect_power(n:&BigInt)->bool{
+    BigInt::perfect_power(n).is_some()
+}
+/// Euler totient function, see [`Factorization::euler_phi`].
+fn euler_phi(n:&BigInt)->BigInt{
+    Factorization::new(n).euler_phi()
+}
+/// Carmichael function, see [`Factorization::carmichael_lambda`].
+fn carmichael_lambda(n:&BigInt)->BigInt{
+    Factorization::new(n).carmichael_lambda()
+}
+/// Möbius function, see [`Factorization::mobius`].
+fn mobius(n:&BigInt)->i8{
+    Factorization::new(n).mobius()
+}
+/// Number of positive divisors, see [`Factorization::number_of_divisors`].
+fn number_of_divisors(n:&BigInt)->BigInt{
+    Factorization::new(n).number_of_divisors()
+}
+/// Sum of the k-th powers of the positive divisors, see [`Factorization::sigma`].
+fn sigma(n:&BigInt,k:u32)->BigInt{
+    Factorization::new(n).sigma(k)
+}
+/// Sorted positive divisors, see [`Factorization::divisors`].
+fn divisors(n:&BigInt)->Vec<BigInt>{
+    Factorization::new(n).divisors()
+}
+fn is_squarefree(n:&BigInt)->bool{
+    Factorization::new(n).is_squarefree()
+}
+fn is_square(n:&BigInt)->bool{
+    if n.sign()==Minus {return false;}
+    let r=n.sqrt();
+    &r*&r==*n
+}
+/// Least non-negative residue of a modulo m.
+fn modulo(a:&BigInt,m:&BigInt)->BigInt{
+    let r=a%m;
+    if r.sign()==Minus {r+m.abs()} else {r}
+}
+}
+
+/// Prime factorization of a non-zero integer as sorted (prime,exponent) pairs, the sign is ignored.
+/// Multiplicative functions are computed from the factorization, so that several of them can be
+/// evaluated on the same integer factoring it only once.
+///
+/// # Example
+/// ```
+/// use algebra::integers::Factorization;
+/// use num_bigint::BigInt;
+/// let f=Factorization::new(&BigInt::from(360));
+/// assert_eq!(f.euler_phi(),BigInt::from(96));
+/// assert_eq!(f.carmichael_lambda(),BigInt::from(12));
+/// assert_eq!(f.mobius(),0);
+/// assert_eq!(f.number_of_divisors(),BigInt::from(24));
+/// assert_eq!(f.sigma(1),BigInt::from(1170));
+/// assert_eq!(f.value(),BigInt::from(360));
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Factorization(pub Vec<(BigInt,u32)>);
+impl From<Vec<(BigInt,u32)>> for Factorization {
+    fn from(factors:Vec<(BigInt,u32)>)->Self{
+        Factorization(factors)
+    }
+}
+impl Factorization {
+    pub fn new(n:&BigInt)->Self{
+        Factorization(factor::factor(n))
+    }
+    /// The factored integer.
+    pub fn value(&self)->BigInt{
+        self.0.iter().map(|(p,e)|p.pow(*e)).product()
+    }
+    /// Euler totient function: phi(n) = prod p^(e-1)*(p-1).
+    pub fn euler_phi(&self)->BigInt{
+        self.0.iter().map(|(p,e)|p.pow(e-1)*(p-BigInt::one())).product()
+    }
+    /// Carmichael function: the exponent of the group (Z/nZ)^*.
+    pub fn carmichael_lambda(&self)->BigInt{
+        self.0.iter().fold(BigInt::one(),|acc,(p,e)|{
+            let lambda= if *p==BigInt::from(2) && *e>=3 {BigInt::one()<<(e-2)} 
+                else {p.pow(e-1)*(p-BigInt::one())};
+            BigInt::lcm(acc, lambda)
+        })
+    }
+    /// Möbius function: 0 if n is not squarefree, (-1)^(number of prime factors) otherwise.
+    pub fn mobius(&self)->i8{
+        if !self.is_squarefree() {return 0;}
+        if self.0.len().is_multiple_of(2) {1} else {-1}
+    }
+    /// Number of positive divisors: tau(n) = prod (e+1).
+    pub fn number_of_divisors(&self)->BigInt{
+        self.0.iter().map(|(_,e)|BigInt::from(e+1)).product()
+    }
+    /// Sum of the k-th powers of the positive divisors: sigma_k(n) = prod (p^(k(e+1))-1)/(p^k-1).
+    /// For k=0 this is the number of divisors.
+    pub fn sigma(&self,k:u32)->BigInt{
+        if k==0 {return self.number_of_divisors();}
+        self.0.iter().map(|(p,e)|{
+            let pk=p.pow(k);
+            (pk.pow(e+1)-BigInt::one())/(pk-BigInt::one())
+        }).product()
+    }
+    /// Positive divisors in increasing order.
+    pub fn divisors(&self)->Vec<BigInt>{
+        let mut divisors=vec![BigInt::one()];
+        for (p,e) in &self.0 {
+            let mut multiples=Vec::with_capacity(divisors.len()*(*e as usize));
+            let mut power=BigInt::one();
+            for _ in 0..*e {
+                power*=p;
+                multiples.extend(divisors.iter().map(|d|d*&power));
+            }
+            divisors.extend(multiples);
+        }
+        divisors.sort();
+        divisors
+    }
+    pub fn is_squarefree(&self)->bool{
+        self.0.iter().all(|(_,e)|*e==1)
+    }
 }
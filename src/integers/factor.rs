@@ -0,0 +1,305 @@
+//! Integer factorization.
+//! The main entry point is [`factor`], which cascades trial division, Pollard rho (Brent variant),
+//! Pollard p-1, Lenstra's elliptic curve method and, for numbers of 40 digits or more, the
+//! quadratic sieve until every factor passes a primality check.
+//! Good references for these algorithms are [H.Cohen A course in computational number theory, chapters 8 and 10]
+//! and [R.Crandall C.Pomerance Prime numbers, a computational perspective, chapters 5 and 7].
+use num_bigint::{BigInt, RandBigInt};
+use num_bigint::Sign::Minus;
+use num_traits::{One, Zero};
+use super::IntUtilities;
+use crate::ring;
+use super::siqs::siqs;
+use super::primes::primes_up_to;
+
+const TRIAL_DIVISION_BOUND:u64=10_000;
+/// Composites with at least this many bits are handed to the quadratic sieve
+/// when a first round of ECM fails.
+const SIQS_BOUND:u64=130;
+/// Factors n into primes. The output is the sorted Vec of (prime,exponent),
+/// the sign of n is ignored and factor(1) is empty.
+///
+/// # Example
+/// ```
+/// use algebra::integers::factor::factor;
+/// use num_bigint::BigInt;
+/// let n=BigInt::from(2u64.pow(5)*3*3*1_000_003*1_000_033);
+/// let expected=vec![(BigInt::from(2),5),(BigInt::from(3),2),(BigInt::from(1_000_003),1),(BigInt::from(1_000_033),1)];
+/// assert_eq!(factor(&n),expected);
+/// ```
+pub fn factor(n:&BigInt)->Vec<(BigInt,u32)>{
+    if n.is_zero() {panic!("Cannot factor zero!")}
+    let n=if n.sign()==Minus {-n} else {n.clone()};
+    let (mut factors,cofactor)=trial_division(&n, TRIAL_DIVISION_BOUND);
+    let mut to_split=vec![(cofactor,1u32)];
+    while let Some((m,e))=to_split.pop() {
+        if m.is_one() {continue;}
+        if BigInt::is_prime(&m) {
+            factors.push((m,e));
+            continue;
+        }
+        if let Some((root,k))=BigInt::perfect_power(&m) {
+            to_split.push((root,e*k));
+            continue;
+        }
+        let d=find_factor(&m);
+        let cofactor=&m/&d;
+        to_split.push((d,e));
+        to_split.push((cofactor,e));
+    }
+    collect_factors(factors)
+}
+/// Merges repeated primes and sorts the factorization.
+fn collect_factors(mut factors:Vec<(BigInt,u32)>)->Vec<(BigInt,u32)>{
+    factors.sort();
+    let mut collected:Vec<(BigInt,u32)>=Vec::new();
+    for (p,e) in factors {
+        match collected.last_mut() {
+            Some(last) if last.0==p => last.1+=e,
+            _ => collected.push((p,e))
+        }
+    }
+    collected
+}
+/// Finds a non-trivial factor of a composite n which is not a perfect power
+/// and has no prime factor below the trial division bound.
+fn find_factor(n:&BigInt)->BigInt{
+    if let Some(d)=pollard_rho(n, 1<<16) {return d;}
+    if let Some(d)=pollard_pm1(n, 100_000) {return d;}
+    let mut b1=2_000u64;
+    let mut curves=25usize;
+    loop {
+        if let Some(d)=ecm(n, b1, curves) {return d;}
+        if n.bits()>=SIQS_BOUND {
+            if let Some(d)=siqs(n) {return d;}
+        }
+        b1*=5;
+        curves*=3;
+    }
+}
+/// Divides n by all primes up to bound. Returns the factors found and the
+/// remaining cofactor.
+///
+/// # Example
+/// ```
+/// use algebra::integers::factor::trial_division;
+/// use num_bigint::BigInt;
+/// let (factors,cofactor)=trial_division(&BigInt::from(2*2*7*1_000_003u64),100);
+/// assert_eq!(factors,vec![(BigInt::from(2),2),(BigInt::from(7),1)]);
+/// assert_eq!(cofactor,BigInt::from(1_000_003));
+/// ```
+pub fn trial_division(n:&BigInt,bound:u64)->(Vec<(BigInt,u32)>,BigInt){
+    let mut m=n.clone();
+    let mut factors=Vec::new();
+    for p in primes_up_to(bound) {
+        let p=BigInt::from(p);
+        if &p*&p>m {break;}
+        let mut e=0u32;
+        while (&m%&p).is_zero() {
+            m/=&p;
+            e+=1;
+        }
+        if e>0 {factors.push((p,e));}
+    }
+    if m>BigInt::one() && m<BigInt::from(bound)*BigInt::from(bound) {
+        factors.push((m,1));
+        m=BigInt::one();
+    }
+    (factors,m)
+}
+/// Pollard rho method in Brent's variant [Algorithm 8.5.2 H.Cohen A course in computational number theory].
+/// Iterates x -> x^2+c for at most max_iterations steps, restarting with a new c
+/// when the cycle closes without a factor. Returns a non-trivial factor of n, if found.
+///
+/// # Example
+/// ```
+/// use algebra::integers::factor::pollard_rho;
+/// use num_bigint::BigInt;
+/// let n=BigInt::from(1_000_003u64*1_000_033);
+/// let d=pollard_rho(&n,1<<16).unwrap();
+/// assert!(d==BigInt::from(1_000_003)||d==BigInt::from(1_000_033));
+/// ```
+pub fn pollard_rho(n:&BigInt,max_iterations:u64)->Option<BigInt>{
+    if n<&BigInt::from(4) {return None;}
+    if BigInt::is_even(n) {return Some(BigInt::from(2));}
+    let mut rng=rand::thread_rng();
+    let mut iterations=0u64;
+    while iterations<max_iterations {
+        let c=rng.gen_bigint_range(&BigInt::one(),&(n-BigInt::one()));
+        let f=|x:&BigInt|->BigInt{(x*x+&c)%n};
+        let mut y=rng.gen_bigint_range(&BigInt::zero(),n);
+        let (mut x,mut ys)=(y.clone(),y.clone());
+        let (mut r,mut q,mut g)=(1u64,BigInt::one(),BigInt::one());
+        let m=128u64;
+        while g.is_one() && iterations<max_iterations {
+            x=y.clone();
+            for _ in 0..r {y=f(&y);}
+            let mut k=0u64;
+            while k<r && g.is_one() {
+                ys=y.clone();
+                for _ in 0..m.min(r-k) {
+                    y=f(&y);
+                    q=q*(&x-&y)%n;
+                }
+                g=BigInt::gcd(q.clone(), n.clone());
+                k+=m;
+            }
+            iterations+=r;
+            r*=2;
+        }
+        if &g==n {
+            loop {
+                ys=f(&ys);
+                g=BigInt::gcd(&x-&ys, n.clone());
+                if !g.is_one() {break;}
+            }
+        }
+        if !g.is_one() && &g!=n {return Some(g);}
+    }
+    None
+}
+/// Pollard p-1 method, stage 1 [Algorithm 8.8.2 H.Cohen A course in computational number theory].
+/// Finds a prime factor p of n when p-1 is bound-powersmooth.
+///
+/// # Example
+/// ```
+/// use algebra::integers::factor::pollard_pm1;
+/// use num_bigint::BigInt;
+/// // 1_451_521-1 = 2^9*3^4*5*7 is smooth, 2_000_003-1 = 2*101*9901 is not
+/// let n=BigInt::from(1_451_521u64*2_000_003);
+/// assert_eq!(pollard_pm1(&n,1000),Some(BigInt::from(1_451_521)));
+/// ```
+pub fn pollard_pm1(n:&BigInt,bound:u64)->Option<BigInt>{
+    let mut a=BigInt::from(2);
+    for (i,p) in primes_up_to(bound).into_iter().enumerate() {
+        let mut q=p;
+        while q<=bound/p {q*=p;}
+        a=a.modpow(&BigInt::from(q), n);
+        if i%64==63 || p*p>bound {
+            let g=BigInt::gcd(&a-BigInt::one(), n.clone());
+            if &g==n {return None;}
+            if !g.is_one() {return Some(g);}
+        }
+    }
+    let g=BigInt::gcd(&a-BigInt::one(), n.clone());
+    if g.is_one() || &g==n {None} else {Some(g)}
+}
+/// Lenstra elliptic curve method on Montgomery curves with Suyama's parametrization
+/// [Algorithm 7.4.4 R.Crandall C.Pomerance Prime numbers]. Stage 1 uses the primes up to b1,
+/// stage 2 the primes up to 100*b1. Tries the given number of random curves and returns a
+/// non-trivial factor of n, if found.
+///
+/// # Example
+/// ```
+/// use algebra::integers::factor::ecm;
+/// use num_bigint::BigInt;
+/// let n=BigInt::from(1_000_003u64*1_000_033);
+/// let d=ecm(&n,2000,50).unwrap();
+/// assert!(d==BigInt::from(1_000_003)||d==BigInt::from(1_000_033));
+/// ```
+pub fn ecm(n:&BigInt,b1:u64,curves:usize)->Option<BigInt>{
+    // Suyama's parameter needs sigma in [6,n-1)
+    if n<&BigInt::from(8) {
+        return (2u8..4).map(BigInt::from).find(|d|d<n && (n%d).is_zero());
+    }
+    let primes=primes_up_to(100*b1);
+    let mut rng=rand::thread_rng();
+    for _ in 0..curves {
+        let sigma=rng.gen_bigint_range(&BigInt::from(6),&(n-BigInt::one()));
+        match ecm_curve(n, &sigma, b1, &primes) {
+            Ok(g)|Err(g) if !g.is_one() && &g!=n => return Some(g),
+            _ => continue
+        }
+    }
+    None
+}
+/// A point on a Montgomery curve By^2=x^3+Ax^2+x in projective (X:Z) coordinates.
+#[derive(Clone)]
+struct XZ {
+    x:BigInt,
+    z:BigInt
+}
+/// Runs one curve. Ok(g) is the gcd found at the end of stage 2, Err(g) is a
+/// non-invertible element met while setting up the curve.
+fn ecm_curve(n:&BigInt,sigma:&BigInt,b1:u64,primes:&[u64])->Result<BigInt,BigInt>{
+    let u=(sigma*sigma-BigInt::from(5))%n;
+    let v=(sigma*BigInt::from(4))%n;
+    let x0=u.modpow(&BigInt::from(3), n);
+    let z0=v.modpow(&BigInt::from(3), n);
+    let num=BigInt::modulo(&((&v-&u).modpow(&BigInt::from(3), n)*(BigInt::from(3)*&u+&v)), n);
+    let den=BigInt::modulo(&(BigInt::from(16)*&x0*&v), n);
+    if den.is_zero() {return Err(n.clone());}
+    let [inv,_,g]=ring::gcdext(&den, n);
+    if !g.is_one() {return Err(g);}
+    let a24=BigInt::modulo(&(num*inv), n);
+    let mut q=XZ{x:x0,z:z0};
+    for p in primes.iter().take_while(|p|**p<=b1) {
+        let mut pe=*p;
+        while pe<=b1/p {pe*=p;}
+        q=ladder(&BigInt::from(pe), &q, &a24, n);
+    }
+    let g=ring::gcd(&q.z, n);
+    if !g.is_one() {return Ok(g);}
+    // stage 2: baby steps j*Q for odd j<D, giant steps m*2D*Q. The giant step m finds the primes
+    // m*2D-j and m*2D+j, covering (m*2D-D,m*2D+D), so the first one is b1/(2D).
+    let d=105u64;
+    let mut baby:Vec<XZ>=Vec::with_capacity((d/2) as usize);
+    let q2=double(&q, &a24, n);
+    baby.push(q.clone());
+    baby.push(add(&q2, &q, &q, n));
+    for j in 2..(d/2) as usize {
+        let next=add(&baby[j-1], &q2, &baby[j-2], n);
+        baby.push(next);
+    }
+    let baby_x_z:Vec<(BigInt,BigInt)>=baby.iter().map(|b|(b.x.clone(),b.z.clone())).collect();
+    let step=ladder(&BigInt::from(2*d), &q, &a24, n);
+    let mut m=b1/(2*d);
+    let mut acc=BigInt::one();
+    if m==0 {
+        // the giant step 0 is the point at infinity (1:0)
+        for (_,bz) in baby_x_z.iter() {acc=acc*bz%n;}
+        m=1;
+    }
+    let mut current=ladder(&BigInt::from(m*2*d), &q, &a24, n);
+    let mut next=ladder(&BigInt::from((m+1)*2*d), &q, &a24, n);
+    let b2=100*b1;
+    while m*2*d<b2+d {
+        for (bx,bz) in baby_x_z.iter() {
+            acc=acc*(&current.x*bz-bx*&current.z)%n;
+        }
+        let after=add(&next, &step, &current, n);
+        current=next;
+        next=after;
+        m+=1;
+    }
+    Ok(ring::gcd(&acc, n))
+}
+fn double(p:&XZ,a24:&BigInt,n:&BigInt)->XZ{
+    let t1=(&p.x+&p.z).pow(2u32)%n;
+    let t2=(&p.x-&p.z).pow(2u32)%n;
+    let t3=&t1-&t2;
+    XZ{x:&t1*&t2%n,z:&t3*(&t2+a24*&t3)%n}
+}
+/// Differential addition: computes P+Q from P, Q and P-Q.
+fn add(p:&XZ,q:&XZ,diff:&XZ,n:&BigInt)->XZ{
+    let u=(&p.x-&p.z)*(&q.x+&q.z);
+    let v=(&p.x+&p.z)*(&q.x-&q.z);
+    let s=(&u+&v)%n;
+    let d=(&u-&v)%n;
+    XZ{x:&diff.z*&s*&s%n,z:&diff.x*&d*&d%n}
+}
+/// Montgomery ladder: computes k*P.
+fn ladder(k:&BigInt,p:&XZ,a24:&BigInt,n:&BigInt)->XZ{
+    let mut r0=p.clone();
+    let mut r1=double(p, a24, n);
+    for bit in BigInt::to_binary(k).iter().skip(1) {
+        if bit.is_one() {
+            r0=add(&r1, &r0, p, n);
+            r1=double(&r1, a24, n);
+        } else {
+            r1=add(&r0, &r1, p, n);
+            r0=double(&r0, a24, n);
+        }
+    }
+    r0
+}
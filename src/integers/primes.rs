@@ -0,0 +1,182 @@
+//! Prime generation: a segmented sieve of Eratosthenes exposed as an iterator,
+//! and random primes of a given bit size. All random generators take a caller
+//! supplied `rand::Rng`, so that results can be reproduced with a seeded generator.
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::{One, Zero};
+use rand::Rng;
+use super::IntUtilities;
+
+const SEGMENT_SIZE:u64=1<<15;
+/// Iterator over all primes in increasing order, produced by a segmented
+/// sieve of Eratosthenes: memory usage is O(sqrt(p)+SEGMENT_SIZE).
+pub struct Primes {
+    base_primes:Vec<u64>,
+    segment:Vec<u64>,
+    position:usize,
+    low:u64
+}
+/// Returns an iterator over all primes.
+///
+/// # Example
+/// ```
+/// use algebra::integers::primes::primes;
+/// let first:Vec<u64>=primes().take(10).collect();
+/// assert_eq!(first,vec![2,3,5,7,11,13,17,19,23,29]);
+/// assert_eq!(primes().nth(9_999),Some(104_729));
+/// ```
+pub fn primes()->Primes{
+    Primes{base_primes:Vec::new(),segment:Vec::new(),position:0,low:0}
+}
+/// Returns the primes up to bound (included).
+///
+/// # Example
+/// ```
+/// use algebra::integers::primes::primes_up_to;
+/// assert_eq!(primes_up_to(20),vec![2,3,5,7,11,13,17,19]);
+/// ```
+pub fn primes_up_to(bound:u64)->Vec<u64>{
+    primes().take_while(|p|*p<=bound).collect()
+}
+impl Primes {
+    /// Sieves the segment [low,low+SEGMENT_SIZE) and moves low forward.
+    fn next_segment(&mut self){
+        let high=self.low+SEGMENT_SIZE;
+        let root=(high as f64).sqrt() as u64+1;
+        if self.base_primes.last().is_none_or(|p|*p<root) {
+            self.base_primes=simple_sieve(2*root);
+        }
+        let mut is_prime=vec![true;SEGMENT_SIZE as usize];
+        for &p in self.base_primes.iter().take_while(|p|**p*(**p)<high) {
+            let mut multiple=(p*p).max(self.low.div_ceil(p)*p);
+            while multiple<high {
+                is_prime[(multiple-self.low) as usize]=false;
+                multiple+=p;
+            }
+        }
+        self.segment=is_prime.iter().enumerate()
+            .filter(|(i,flag)|**flag && self.low+*i as u64>=2)
+            .map(|(i,_)|self.low+i as u64)
+            .collect();
+        self.position=0;
+        self.low=high;
+    }
+}
+impl Iterator for Primes {
+    type Item=u64;
+    fn next(&mut self)->Option<u64>{
+        while self.position>=self.segment.len() {
+            if self.low>u64::MAX-SEGMENT_SIZE {return None;}
+            self.next_segment();
+        }
+        self.position+=1;
+        Some(self.segment[self.position-1])
+    }
+}
+fn simple_sieve(bound:u64)->Vec<u64>{
+    let bound=bound as usize;
+    let mut sieve=vec![true;bound+1];
+    let mut primes=Vec::new();
+    for i in 2..=bound {
+        if !sieve[i] {continue;}
+        primes.push(i as u64);
+        let mut j=i*i;
+        while j<=bound {
+            sieve[j]=false;
+            j+=i;
+        }
+    }
+    primes
+}
+/// Returns a uniformly chosen prime with exactly `bits` bits.
+///
+/// # Example
+/// ```
+/// use algebra::integers::primes::random_prime;
+/// use algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// use rand::SeedableRng;
+/// let mut rng=rand::rngs::StdRng::seed_from_u64(42);
+/// let p=random_prime(256,&mut rng);
+/// assert_eq!(p.bits(),256);
+/// assert!(BigInt::is_prime(&p));
+/// ```
+pub fn random_prime<R:Rng+?Sized>(bits:u64,rng:&mut R)->BigInt{
+    if bits<2 {panic!("There are no primes with {} bits!",bits)}
+    loop {
+        let candidate=random_odd_with_bits(bits, rng);
+        if BigInt::is_prime(&candidate) {return candidate;}
+    }
+}
+/// Returns a safe prime p=2q+1, with q prime, having exactly `bits` bits.
+///
+/// # Example
+/// ```
+/// use algebra::integers::primes::random_safe_prime;
+/// use algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// use rand::SeedableRng;
+/// let mut rng=rand::rngs::StdRng::seed_from_u64(42);
+/// let p=random_safe_prime(64,&mut rng);
+/// assert_eq!(p.bits(),64);
+/// assert!(BigInt::is_prime(&p) && BigInt::is_prime(&(p>>1)));
+/// ```
+pub fn random_safe_prime<R:Rng+?Sized>(bits:u64,rng:&mut R)->BigInt{
+    if bits<3 {panic!("There are no safe primes with {} bits!",bits)}
+    let two=BigInt::from(2);
+    loop {
+        let q=random_odd_with_bits(bits-1, rng);
+        let p=&q*&two+BigInt::one();
+        // cheap strong tests on both numbers before the full primality checks
+        if bits>8 && !(BigInt::is_strong_probable_prime(&q, &two) && BigInt::is_strong_probable_prime(&p, &two)) {continue;}
+        if BigInt::is_prime(&q) && BigInt::is_prime(&p) {return p;}
+    }
+}
+/// Returns a random prime p with exactly `bits` bits and p = a mod m.
+/// The output is None if gcd(a,m)!=1 or if the residue class contains no prime
+/// of the required size.
+///
+/// # Example
+/// ```
+/// use algebra::integers::primes::random_prime_congruent;
+/// use algebra::integers::IntUtilities;
+/// use num_bigint::BigInt;
+/// use rand::SeedableRng;
+/// let mut rng=rand::rngs::StdRng::seed_from_u64(42);
+/// let p=random_prime_congruent(128,&BigInt::from(3),&BigInt::from(4),&mut rng).unwrap();
+/// assert_eq!(p.bits(),128);
+/// assert_eq!(&p%4,BigInt::from(3));
+/// assert!(BigInt::is_prime(&p));
+/// assert_eq!(random_prime_congruent(128,&BigInt::from(2),&BigInt::from(4),&mut rng),None);
+/// ```
+pub fn random_prime_congruent<R:Rng+?Sized>(bits:u64,a:&BigInt,m:&BigInt,rng:&mut R)->Option<BigInt>{
+    if bits<2 || m<=&BigInt::zero() || !BigInt::gcd(a.clone(), m.clone()).is_one() {return None;}
+    let a=BigInt::modulo(a, m);
+    let low=BigInt::one()<<(bits-1);
+    let high=BigInt::one()<<bits;
+    // k ranges over the integers with low <= a+k*m < high
+    let k_low=if low<=a {BigInt::zero()} else {(&low-&a+m-BigInt::one())/m};
+    let k_high=(&high-&a+m-BigInt::one())/m;
+    if k_low>=k_high {return None;}
+    if &k_high-&k_low<BigInt::from(4096) {
+        let mut candidates:Vec<BigInt>=num_iter(&k_low, &k_high).map(|k|&a+k*m)
+            .filter(BigInt::is_prime).collect();
+        if candidates.is_empty() {return None;}
+        let i=rng.gen_range(0..candidates.len());
+        return Some(candidates.swap_remove(i));
+    }
+    loop {
+        let k=rng.gen_bigint_range(&k_low, &k_high);
+        let candidate=&a+k*m;
+        if BigInt::is_prime(&candidate) {return Some(candidate);}
+    }
+}
+fn num_iter(low:&BigInt,high:&BigInt)->impl Iterator<Item=BigInt>{
+    let high=high.clone();
+    std::iter::successors(Some(low.clone()),|k|Some(k+BigInt::one())).take_while(move |k|*k<high)
+}
+fn random_odd_with_bits<R:Rng+?Sized>(bits:u64,rng:&mut R)->BigInt{
+    let mut n:BigInt=rng.gen_biguint(bits).into();
+    n|=BigInt::one()<<(bits-1);
+    if bits>2 {n|=BigInt::one();}
+    n
+}
@@ -0,0 +1,342 @@
+//! Self-initializing quadratic sieve (SIQS) for integers of 40 to 100 digits.
+//! The implementation follows [S.Contini Factoring integers with the self-initializing quadratic sieve]
+//! and [R.Crandall C.Pomerance Prime numbers, a computational perspective, chapter 6]:
+//! Knuth-Schroeppel multiplier, single large prime variation, Gray code switching of
+//! the b coefficients and Gaussian elimination over GF(2) for the linear algebra step.
+use std::collections::{HashMap, HashSet};
+use num_bigint::BigInt;
+use num_bigint::Sign::Minus;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use rand::Rng;
+use crate::intmod::PrimeField;
+use super::IntUtilities;
+use super::factor::pollard_rho;
+use super::primes::{primes, primes_up_to};
+
+/// Factor base size and sieve half width M, indexed by number of decimal digits.
+const PARAMETERS:[(usize,usize,usize);17]=[
+    (20,120,16_384),(25,160,16_384),(30,220,32_768),(35,300,32_768),(40,450,32_768),
+    (45,650,32_768),(50,900,32_768),(55,1_900,65_536),(60,2_600,65_536),(65,3_600,65_536),
+    (70,4_800,65_536),(75,6_300,98_304),(80,8_200,98_304),(85,10_500,98_304),(90,13_500,131_072),
+    (95,17_000,131_072),(100,22_000,131_072)];
+/// Number of bits by which the sieve threshold is lowered below the expected size of a
+/// partial relation, to make up for the rounding of the logarithms and the unsieved small primes.
+const THRESHOLD_SLACK:f64=14.0;
+/// Primes below this bound are not sieved, their contribution is accounted for in the threshold.
+const SMALL_PRIME_BOUND:u32=32;
+/// Iterations of Pollard rho for n < 2^64: far above the 2^16 expected for the smallest factor.
+const RHO_ITERATIONS:u64=1<<26;
+/// Multipliers tried by the Knuth-Schroeppel algorithm.
+const MULTIPLIERS:[u32;24]=[1,2,3,5,6,7,10,11,13,14,15,17,19,21,22,23,26,29,30,31,33,35,37,41];
+
+struct FactorBase {
+    primes:Vec<u32>,
+    sqrt:Vec<u32>,
+    logp:Vec<u8>
+}
+/// A relation y^2 = sign*prod p_i^e_i * extra^2 modulo n.
+#[derive(Clone)]
+struct Relation {
+    y:BigInt,
+    exponents:Vec<(usize,u32)>,
+    extra:BigInt
+}
+/// Finds a non-trivial factor of an odd composite n. Returns None if n is a prime or a perfect power,
+/// which the quadratic sieve cannot split. Numbers below 2^64 are handed to Pollard rho.
+///
+/// # Example
+/// ```
+/// use algebra::integers::siqs::siqs;
+/// use num_bigint::BigInt;
+/// let p:BigInt="1000000000000037".parse().unwrap();
+/// let q:BigInt="10000000000000061".parse().unwrap();
+/// let d=siqs(&(&p*&q)).unwrap();
+/// assert!(d==p||d==q);
+/// ```
+pub fn siqs(n:&BigInt)->Option<BigInt>{
+    if BigInt::is_prime(n) || BigInt::perfect_power(n).is_some() {return None;}
+    if BigInt::is_even(n) {return Some(BigInt::from(2));}
+    if n.bits()<=64 {return pollard_rho(n, RHO_ITERATIONS);}
+    let digits=n.to_string().len();
+    let (_,fb_size,m)=*PARAMETERS.iter().find(|p|p.0>=digits).unwrap_or(&PARAMETERS[16]);
+    let k=knuth_schroeppel(n);
+    let kn=n*BigInt::from(k);
+    let mut fb=match factor_base(n, &kn, fb_size) {
+        Ok(fb)=>fb,
+        Err(p)=>return Some(p)
+    };
+    let pmax=*fb.primes.last().unwrap() as u64;
+    let large_bound=64*pmax;
+    // logarithms are scaled so that the threshold fits below 128: a sieve location is a candidate
+    // when the high bit of its byte gets set
+    let log_threshold=(kn.bits() as f64)/2.0+(m as f64).log2()-0.5-(large_bound as f64).log2()-THRESHOLD_SLACK;
+    let scale=(100.0/log_threshold).min(1.0);
+    let threshold=(scale*log_threshold) as u8;
+    fb.logp=fb.primes.iter().map(|p|(scale*(*p as f64).log2()).round() as u8).collect();
+    let mut rng=rand::thread_rng();
+    let mut used_a:HashSet<BigInt>=HashSet::new();
+    let mut relations:Vec<Relation>=Vec::new();
+    let mut partials:HashMap<u64,Relation>=HashMap::new();
+    let mut needed=fb.primes.len()+32;
+    let mut sieve=vec![0u8;2*m];
+    loop {
+        let (a,factors_a)=choose_a(&kn, &fb, m, &mut used_a, &mut rng);
+        let s=factors_a.len();
+        let mut big_b=Vec::with_capacity(s);
+        for l in &factors_a {
+            let q=fb.primes[*l];
+            let a_over_q=&a/BigInt::from(q);
+            let inv=inverse_mod((&a_over_q%q).to_u64().unwrap(), q as u64);
+            let mut gamma=(fb.sqrt[*l] as u64)*inv%(q as u64);
+            if gamma>(q as u64)/2 {gamma=q as u64-gamma;}
+            big_b.push(a_over_q*BigInt::from(gamma));
+        }
+        let mut signs=vec![1i8;s];
+        let mut b:BigInt=big_b.iter().sum();
+        let mut in_a=vec![false;fb.primes.len()];
+        for l in &factors_a {in_a[*l]=true;}
+        let mut root1=vec![0u32;fb.primes.len()];
+        let mut root2=vec![0u32;fb.primes.len()];
+        let mut b_ainv2=vec![vec![0u32;fb.primes.len()];s];
+        for (j,&p) in fb.primes.iter().enumerate() {
+            if in_a[j] {continue;}
+            let p64=p as u64;
+            let ainv=inverse_mod((&a%p).to_u64().unwrap(), p64);
+            if ainv==0 {continue;}
+            let b_mod=BigInt::modulo(&b, &BigInt::from(p)).to_u64().unwrap();
+            let t=fb.sqrt[j] as u64;
+            root1[j]=(ainv*((t+p64-b_mod)%p64)%p64) as u32;
+            root2[j]=(ainv*((2*p64-t-b_mod)%p64)%p64) as u32;
+            for l in 0..s {
+                let bl=(&big_b[l]%p).to_u64().unwrap();
+                b_ainv2[l][j]=(2*bl%p64*ainv%p64) as u32;
+            }
+        }
+        for i in 0..(1usize<<(s-1)) {
+                if i>0 {
+                let v=i.trailing_zeros() as usize;
+                let sign=signs[v];
+                if sign>0 {b-=&big_b[v]*2;} else {b+=&big_b[v]*2;}
+                signs[v]*=-1;
+                for (j,&p) in fb.primes.iter().enumerate() {
+                    if in_a[j] {continue;}
+                    let delta=if sign>0 {b_ainv2[v][j]} else {p-b_ainv2[v][j]};
+                    root1[j]=((root1[j] as u64+delta as u64)%p as u64) as u32;
+                    root2[j]=((root2[j] as u64+delta as u64)%p as u64) as u32;
+                }
+            }
+            let c=(&b*&b-&kn)/&a;
+            sieve_interval(&mut sieve, 128-threshold, &fb, &in_a, &root1, &root2, m);
+            let candidates=sieve.chunks_exact(8).enumerate()
+                .filter(|(_,chunk)|u64::from_ne_bytes((*chunk).try_into().unwrap())&0x8080_8080_8080_8080!=0)
+                .flat_map(|(i,chunk)|chunk.iter().enumerate().filter(|(_,v)|**v>=128).map(move |(j,_)|8*i+j));
+            for idx in candidates {
+                let x=idx as i64-m as i64;
+                let q_x=(&a*BigInt::from(x)+&b*2)*BigInt::from(x)+&c;
+                let y=&a*BigInt::from(x)+&b;
+                if let Some((exponents,cofactor))=trial_divide(&q_x, x, &fb, &in_a, &root1, &root2) {
+                    let relation=Relation{y, exponents, extra:BigInt::one()};
+                    if cofactor==1 {
+                        relations.push(relation);
+                    } else if cofactor<large_bound {
+                        match partials.remove(&cofactor) {
+                            Some(other)=>relations.push(combine(&relation, &other, cofactor, n)),
+                            None=>{partials.insert(cofactor, relation);}
+                        }
+                    }
+                }
+            }
+            if relations.len()>=needed {
+                if let Some(d)=linear_algebra(&relations, fb.primes.len(), &fb, n) {return Some(d);}
+                needed+=32;
+            }
+        }
+    }
+}
+/// Chooses the multiplier k maximizing the Knuth-Schroeppel function, so that
+/// many small primes are quadratic residues modulo kn.
+fn knuth_schroeppel(n:&BigInt)->u32{
+    let small_primes:Vec<u32>=primes_up_to(1000).into_iter().skip(1).map(|p|p as u32).collect();
+    let mut best=(1u32,f64::MIN);
+    for &k in MULTIPLIERS.iter() {
+        let kn=n*BigInt::from(k);
+        let mut score=-0.5*(k as f64).ln();
+        score+=match (&kn%8u32).to_u32().unwrap() {
+            1=>2.0*2f64.ln(),
+            5=>2f64.ln(),
+            3|7=>0.5*2f64.ln(),
+            _=>0.0
+        };
+        for &p in &small_primes {
+            let lp=(p as f64).ln();
+            if k%p==0 {score+=lp/(p as f64);}
+            else if BigInt::kronecker(&(&kn%p), &BigInt::from(p))==1 {score+=2.0*lp/((p-1) as f64);}
+        }
+        if score>best.1 {best=(k,score);}
+    }
+    best.0
+}
+/// Collects the primes p with (kn/p)!=-1 and the square roots of kn modulo p.
+/// Returns Err(p) if a prime of the factor base divides n.
+fn factor_base(n:&BigInt,kn:&BigInt,size:usize)->Result<FactorBase,BigInt>{
+    let mut fb=FactorBase{primes:vec![2],sqrt:vec![(kn%2u32).to_u32().unwrap()],logp:Vec::new()};
+    for p in primes().skip(1).map(|p|p as u32) {
+        if fb.primes.len()>=size {break;}
+        let field=PrimeField::new_unchecked(BigInt::from(p));
+        if (n%p).is_zero() {return Err(BigInt::from(p));}
+        let residue=field.new(kn.clone());
+        if BigInt::kronecker(&residue.n, &BigInt::from(p))==-1 {continue;}
+        let mut residue=residue;
+        let root=residue.sqrt_mod_prime();
+        fb.primes.push(p);
+        fb.sqrt.push(root.n.to_u32().unwrap());
+    }
+    Ok(fb)
+}
+/// Chooses a=q_1*...*q_s close to sqrt(2kn)/M with the q_i in the factor base.
+fn choose_a<R:Rng>(kn:&BigInt,fb:&FactorBase,m:usize,used:&mut HashSet<BigInt>,rng:&mut R)->(BigInt,Vec<usize>){
+    let target=(kn*BigInt::from(2)).sqrt()/BigInt::from(m);
+    let log_target=(target.bits() as f64)*2f64.ln();
+    let pmax=*fb.primes.last().unwrap() as f64;
+    let ideal=(2000f64).min(pmax/2.0);
+    let s=((log_target/ideal.ln()).round() as usize).max(1);
+    let q_ideal=(log_target/(s as f64)).exp();
+    let candidates:Vec<usize>=(1..fb.primes.len())
+        .filter(|&j|{
+            let p=fb.primes[j] as f64;
+            p>=q_ideal/2.0 && p<=q_ideal*2.0 && fb.sqrt[j]!=0
+        })
+        .collect();
+    let candidates=if candidates.len()<s+2 {
+        (fb.primes.len()/2..fb.primes.len()).filter(|&j|fb.sqrt[j]!=0).collect()
+    } else {candidates};
+    loop {
+        let mut chosen:Vec<usize>=Vec::with_capacity(s);
+        let mut a=BigInt::one();
+        while chosen.len()<s-1 {
+            let j=candidates[rng.gen_range(0..candidates.len())];
+            if chosen.contains(&j) {continue;}
+            chosen.push(j);
+            a*=fb.primes[j];
+        }
+        let last=(&target/&a).to_u64().unwrap_or(u64::MAX);
+        let best=candidates.iter()
+            .filter(|j|!chosen.contains(j))
+            .min_by_key(|&&j|(fb.primes[j] as u64).abs_diff(last));
+        if let Some(&j)=best {
+            chosen.push(j);
+            a*=fb.primes[j];
+            if used.insert(a.clone()) {
+                chosen.sort();
+                return (a,chosen);
+            }
+        }
+    }
+}
+fn sieve_interval(sieve:&mut [u8],init:u8,fb:&FactorBase,in_a:&[bool],root1:&[u32],root2:&[u32],m:usize){
+    sieve.iter_mut().for_each(|v|*v=init);
+    let len=sieve.len();
+    for (j,&p) in fb.primes.iter().enumerate() {
+        if p<SMALL_PRIME_BOUND || in_a[j] {continue;}
+        let p=p as usize;
+        let logp=fb.logp[j];
+        let offset=m%p;
+        let start1=(root1[j] as usize+offset)%p;
+        let start2=(root2[j] as usize+offset)%p;
+        for start in [start1,start2] {
+            let mut i=start;
+            while i<len {
+                sieve[i]=sieve[i].saturating_add(logp);
+                i+=p;
+            }
+            if start1==start2 {break;}
+        }
+    }
+}
+/// Factors a*Q(x) over the factor base. Returns the exponent vector (index 0 is the sign)
+/// and the cofactor, if the cofactor fits in a u64.
+fn trial_divide(q_x:&BigInt,x:i64,fb:&FactorBase,in_a:&[bool],root1:&[u32],root2:&[u32])->Option<(Vec<(usize,u32)>,u64)>{
+    let mut exponents:Vec<(usize,u32)>=Vec::new();
+    if q_x.sign()==Minus {exponents.push((0,1));}
+    let mut q=q_x.abs();
+    if q.is_zero() {return None;}
+    for (j,&p) in fb.primes.iter().enumerate() {
+        let xm=x.rem_euclid(p as i64) as u32;
+        let candidate= j==0 || in_a[j] || xm==root1[j] || xm==root2[j];
+        let mut e=if in_a[j] {1} else {0};
+        if candidate {
+            while (&q%p).is_zero() {
+                q/=p;
+                e+=1;
+            }
+        }
+        if e>0 {exponents.push((j+1,e));}
+    }
+    q.to_u64().map(|cofactor|(exponents,cofactor))
+}
+/// Combines two partial relations sharing the large prime.
+fn combine(r1:&Relation,r2:&Relation,large:u64,n:&BigInt)->Relation{
+    let mut exponents:HashMap<usize,u32>=r1.exponents.iter().copied().collect();
+    for (j,e) in &r2.exponents {*exponents.entry(*j).or_insert(0)+=e;}
+    let mut exponents:Vec<(usize,u32)>=exponents.into_iter().collect();
+    exponents.sort();
+    Relation{y:&r1.y*&r2.y%n, exponents, extra:BigInt::from(large)}
+}
+/// Finds dependencies among the exponent vectors modulo 2 by Gaussian elimination and
+/// tries to split n with each of them.
+fn linear_algebra(relations:&[Relation],fb_len:usize,fb:&FactorBase,n:&BigInt)->Option<BigInt>{
+    let columns=fb_len+1;
+    let rows=relations.len();
+    let words=(columns+rows).div_ceil(64);
+    let mut matrix:Vec<Vec<u64>>=relations.iter().enumerate().map(|(i,r)|{
+        let mut row=vec![0u64;words];
+        for (j,e) in &r.exponents {
+            if e%2==1 {row[j/64]^=1<<(j%64);}
+        }
+        let h=columns+i;
+        row[h/64]|=1<<(h%64);
+        row
+    }).collect();
+    let mut is_pivot=vec![false;rows];
+    for col in 0..columns {
+        let (w,bit)=(col/64,1u64<<(col%64));
+        let Some(pivot)=(0..rows).find(|&r|!is_pivot[r] && matrix[r][w]&bit!=0) else {continue};
+        is_pivot[pivot]=true;
+        let pivot_row=matrix[pivot].clone();
+        for (r,row) in matrix.iter_mut().enumerate() {
+            if r!=pivot && row[w]&bit!=0 {
+                row.iter_mut().zip(&pivot_row).for_each(|(a,b)|*a^=b);
+            }
+        }
+    }
+    for (r,row) in matrix.iter().enumerate() {
+        if is_pivot[r] {continue;}
+        let dependency:Vec<usize>=(0..rows).filter(|i|{let h=columns+i; row[h/64]>>(h%64)&1==1}).collect();
+        let mut x=BigInt::one();
+        let mut z=BigInt::one();
+        let mut exponents=vec![0u32;columns];
+        for i in dependency {
+            x=x*&relations[i].y%n;
+            z=z*&relations[i].extra%n;
+            for (j,e) in &relations[i].exponents {exponents[*j]+=e;}
+        }
+        for (j,e) in exponents.iter().enumerate().skip(1) {
+            z=z*BigInt::from(fb.primes[j-1]).modpow(&BigInt::from(e/2), n)%n;
+        }
+        let g=BigInt::gcd(&x-&z, n.clone());
+        if !g.is_one() && &g!=n {return Some(g);}
+    }
+    None
+}
+/// Inverse of a modulo the prime p, or 0 if a is divisible by p.
+fn inverse_mod(a:u64,p:u64)->u64{
+    let (mut r0,mut r1)=(p as i128,(a%p) as i128);
+    let (mut t0,mut t1)=(0i128,1i128);
+    while r1!=0 {
+        let q=r0/r1;
+        (r0,r1)=(r1,r0-q*r1);
+        (t0,t1)=(t1,t0-q*t1);
+    }
+    if r0!=1 {return 0;}
+    t0.rem_euclid(p as i128) as u64
+}
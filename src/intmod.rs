@@ -1,18 +1,49 @@
-
+pub mod dlog;
+pub mod modn;
+pub mod sqrt;
+pub mod nth_root;
+pub mod montgomery;
+pub mod fp64;
+pub mod fp;
+pub mod multiexp;
+pub mod ct;
 use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt::{Debug,self}};
 use num_bigint::Sign::Minus;
 use num_bigint::RandBigInt;
 use num_bigint::BigInt;
 use num_traits::{Zero,One};
-use crate::{integers::IntUtilities, field::Field};
-#[derive(Debug,PartialEq)]
-pub enum MathError {
-    QuadraticNonResidueModP
-}
+use crate::{integers::{IntUtilities, Factorization}, field::Field, ring};
+use self::modn::ModN;
+use crate::exponentiation::{pow_sliding_window, pow_ladder};
+/// The error type of modular arithmetic, kept as a name for the crate-wide [`AlgebraError`](crate::error::AlgebraError).
+pub use crate::error::AlgebraError as MathError;
 pub type ModResult=Result<Mod,MathError>;
+/// The field of integers modulo a prime p.
+/// The tuple constructor `PrimeField(p)` is unchecked, like [`PrimeField::new_unchecked`]: with a
+/// composite p, elements are integers modulo p and inversion can fail for non-zero elements.
+/// Use [`PrimeField::try_new`] for moduli that are not known to be prime.
 #[derive(Debug, Clone,Eq,PartialEq,PartialOrd, Ord)]
 pub struct PrimeField(pub BigInt);
 impl PrimeField {
+/// Checked constructor: returns the field of integers modulo p, or
+/// `MathError::NonPrimeModulus` if p is not a (positive) prime.
+/// 
+/// # Example
+/// ```
+/// use algebra::intmod::{PrimeField,MathError};
+/// use num_bigint::BigInt;
+/// let z13=PrimeField::try_new(BigInt::from(13)).unwrap();
+/// assert_eq!(z13,PrimeField(BigInt::from(13)));
+/// assert_eq!(PrimeField::try_new(BigInt::from(91)),Err(MathError::NonPrimeModulus(BigInt::from(91))));
+/// ```
+    pub fn try_new(p:BigInt)->Result<PrimeField,MathError>{
+        if BigInt::is_prime(&p) {Ok(PrimeField(p))} else {Err(MathError::NonPrimeModulus(p))}
+    }
+/// Builds the field without checking that p is prime. Only use it with moduli
+/// that are already known to be prime, e.g. the modulus of an existing field.
+    pub fn new_unchecked(p:BigInt)->PrimeField{
+        PrimeField(p)
+    }
     pub fn random(&self)->Mod{
         let mut rng = rand::thread_rng();
         let n= rng.gen_bigint_range(&BigInt::zero(),&self.0.clone());
@@ -27,6 +58,52 @@ impl PrimeField {
     pub fn one(&self)->Mod{
         Mod::new(BigInt::one(),self.clone())
     }
+/// Order of the multiplicative group, that is p-1.
+    pub fn group_order(&self)->BigInt{
+        &self.0-BigInt::one()
+    }
+/// Smallest positive integer which generates the multiplicative group.
+/// 
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z41=PrimeField::try_new(BigInt::from(41)).unwrap();
+/// assert_eq!(z41.primitive_root(),z41.new(BigInt::from(6)));
+/// ```
+    pub fn primitive_root(&self)->Mod{
+        let factorization=Factorization::new(&self.group_order());
+        let mut g=self.one();
+        loop {
+            if g.is_primitive_root_with(&factorization) {return g;}
+            g=&g+&self.one();
+        }
+    }
+/// Uniformly chosen generator of the multiplicative group.
+    pub fn random_primitive_root(&self)->Mod{
+        let factorization=Factorization::new(&self.group_order());
+        loop {
+            let g=self.random();
+            if g.is_primitive_root_with(&factorization) {return g;}
+        }
+    }
+/// Returns an element of multiplicative order exactly n, or None if n does not divide p-1.
+/// 
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z97=PrimeField::try_new(BigInt::from(97)).unwrap();
+/// let omega=z97.root_of_unity(&BigInt::from(32)).unwrap();
+/// assert_eq!(omega.multiplicative_order(),Some(BigInt::from(32)));
+/// assert_eq!(z97.root_of_unity(&BigInt::from(5)),None);
+/// ```
+    pub fn root_of_unity(&self,n:&BigInt)->Option<Mod>{
+        let order=self.group_order();
+        if n<=&BigInt::zero() || !(&order%n).is_zero() {return None;}
+        let mut g=self.primitive_root();
+        Some(g.pow_mod(&(order/n)))
+    }
 }
 #[derive(Debug, Clone,Eq,PartialEq,PartialOrd, Ord)]
 pub struct Mod {
@@ -46,7 +123,7 @@ impl fmt::Display for Mod {
 /// use algebra::intmod::PrimeField;
 /// use algebra::field::Field;
 /// use num_bigint::BigInt;
-/// let z13=PrimeField(BigInt::from(13));
+/// let z13=PrimeField::try_new(BigInt::from(13)).unwrap();
 /// let x=z13.new(BigInt::from(12));
 /// let y=z13.new(BigInt::from(3));
 /// let expected_add=z13.new(BigInt::from(2));
@@ -66,138 +143,82 @@ impl fmt::Display for Mod {
 /// ```
 impl Add<Mod> for Mod {
     type Output = Mod;
-    
     fn add(self, rhs: Self) -> Self::Output {
-        if self.modulus==rhs.modulus{
-                let modulus=self.modulus.0;
-                let mut sum = self.n+rhs.n;
-                if sum>=modulus{
-                    sum = sum-&modulus;
-                    Mod::new(sum, PrimeField(modulus))}
-                    else {Mod::new(sum, PrimeField(modulus))}}
-                else {panic!("cannot add different modulus")}
+        &self+&rhs
+    }
 }
+impl<'b> Add<&'b Mod> for &'b Mod {
+    type Output = Mod;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
 }
-impl<'a, 'b> Add<&'b Mod> for &'b Mod {
-        type Output = Mod;
-        fn add(self, rhs: Self) -> Self::Output {
-            if self.modulus==rhs.modulus{
-                let modulus=self.modulus.0.clone();
-                let mut sum = &self.n+&rhs.n;
-                if sum>=modulus{
-                    sum = sum-&modulus;
-                    Mod::new(sum, PrimeField(modulus))}
-                    else {Mod::new(sum, PrimeField(modulus))}}
-                else {panic!("cannot add different modulus")}
-                
-            }
+impl<'a> Add<&'a Mod> for Mod {
+    type Output = Mod;
+    fn add(self, rhs: &'a Mod) -> Self::Output {
+        &self+rhs
     }
-    impl<'a> Add<&'a Mod> for Mod {
-        type Output = Mod;
-        fn add(self, rhs: &'a Mod) -> Self::Output {
-            if self.modulus==rhs.modulus{
-                let modulus=self.modulus.0.clone();
-                let mut sum = &self.n+&rhs.n;
-                if sum>=modulus{
-                    sum = sum-&modulus;
-                    Mod::new(sum, PrimeField(modulus))}
-                    else {Mod::new(sum, PrimeField(modulus))}}
-                else {panic!("cannot add different modulus")}
-                
-            }
+}
+impl <'a> Add<&'a mut Mod> for Mod {
+    type Output=Mod;
+    fn add(self, rhs: &'a mut Mod) -> Self::Output {
+        &self+&*rhs
     }
+}
 impl Mul<Mod> for Mod {
-        type Output = Self;
-        fn mul(self, rhs: Self) -> Self::Output {
-            if self.modulus==rhs.modulus {
-                Mod::new((self.n*rhs.n)%&self.modulus.0,rhs.modulus)}
-                else {panic!("You can not multiply to different mod!")}
-        }
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self*&rhs
     }
-impl<'a,'b> Mul<&'b Mod> for &'b Mod {
-        type Output = Mod;
-        fn mul(self, rhs: Self) -> Self::Output {
-            if rhs.modulus==self.modulus {
-                Mod::new(&self.n*&rhs.n,self.modulus.clone())}
-                else {panic!("You can not multiply two different mod!")}
-        }
+}
+impl<'b> Mul<&'b Mod> for &'b Mod {
+    type Output = Mod;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).unwrap_or_else(|error|panic!("{}",error))
     }
-impl <'a> Add<&'a mut Mod> for Mod {
-    type Output=Mod;
-    fn add(self, rhs: &'a mut Mod) -> Self::Output {
-        if self.modulus==rhs.modulus{
-            let modulus=self.modulus.0.clone();
-            let mut sum = &self.n+&rhs.n;
-            if sum>=modulus{
-                sum = sum-&modulus;
-                Mod::new(sum, PrimeField(modulus))}
-                else {Mod::new(sum, PrimeField(modulus))}}
-            else {panic!("cannot add different modulus")}
-            
-        }
-    
 }
 impl<'a> Mul<&'a Mod> for Mod {
     type Output = Mod;
     fn mul(self, rhs: &'a Mod) -> Self::Output {
-            if rhs.modulus==self.modulus {
-                Mod::new(&self.n*&rhs.n,self.modulus.clone())}
-                else {panic!("You can not multiply two different mod!")}
-        }
+        &self*rhs
     }
-
-    impl<'a> Mul<Mod> for &'a mut Mod {
-        type Output = Mod;
-        fn mul(self, rhs: Mod) -> Self::Output {
-            if rhs.modulus==self.modulus {
-                Mod::new(&self.n*&rhs.n,self.modulus.clone())}
-                else {panic!("You can not multiply two different mod!")}
-        }
+}
+impl Mul<Mod> for &mut Mod {
+    type Output = Mod;
+    fn mul(self, rhs: Mod) -> Self::Output {
+        &*self*&rhs
     }
-
+}
 impl Neg for Mod{
-        type Output = Mod;
-        fn neg(self) -> Self::Output {
-            Mod::new(&self.modulus.0-&self.n,self.modulus.clone())
-        }
+    type Output = Mod;
+    fn neg(self) -> Self::Output {
+        Mod::new(&self.modulus.0-&self.n,self.modulus.clone())
     }
+}
 impl Sub<Mod> for Mod {
-        type Output = Mod;
-        fn sub(self, rhs: Self) -> Self::Output {
-            if rhs.modulus==self.modulus {
-            self+rhs.neg()} 
-            else {panic!("You can not subtruct two differents mod!")}
-    
-        }   
+    type Output = Mod;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self-&rhs
     }
-impl<'a, 'b> Sub<&'b Mod> for &'b Mod {
+}
+impl<'b> Sub<&'b Mod> for &'b Mod {
     type Output = Mod;
-        fn sub(self, rhs: Self)->Mod {
-            let mut aux=self.n.clone();
-            let modulus=self.modulus.0.clone();
-                if rhs.modulus==self.modulus {
-                    if self.n<rhs.n {
-                         aux=&self.n+modulus;
-                    }
-                self.modulus.new(aux-&rhs.n)
-                }
-                else {panic!("You can not subtract two different mod!")}
-                
-            }
+    fn sub(self, rhs: Self)->Mod {
+        self.checked_sub(rhs).unwrap_or_else(|error|panic!("{}",error))
     }
+}
 impl Div<Mod> for Mod {
-        type Output = Mod;
-        fn div(self:Self,other:Self)->Self::Output{
-            self*other.inverse()
-        }
+    type Output = Mod;
+    fn div(self,other:Self)->Self::Output{
+        &self/&other
     }
-impl<'a, 'b> Div<&'b Mod> for &'b Mod{
-        type Output = Mod;
-        fn div(self:Self,other:Self)->Self::Output{
-            self*&other.inverse()
-        }
+}
+impl<'b> Div<&'b Mod> for &'b Mod{
+    type Output = Mod;
+    fn div(self,other:Self)->Self::Output{
+        self.checked_div(other).unwrap_or_else(|error|panic!("{}",error))
     }
-    
+}
 impl Field for Mod {
     fn one(&self)->Self {
         Mod::new(BigInt::one(), self.modulus.clone())
@@ -213,39 +234,73 @@ impl Field for Mod {
         self.n.is_one()
     }
     fn inverse(&self)->Self {
-            if self.n==BigInt::one(){return self.clone();}
-            let a=self.n.clone();
-            let b=self.modulus.0.clone();
-            let bezout=BigInt::gcdext(a, b);
-            if bezout[2]>BigInt::one() {panic!("Not an invertible element!")}
-            else  {
-                Mod::new(bezout[0].clone(), self.modulus.clone())
-            }
-        }
-    
+        self.try_inverse().unwrap_or_else(|error|panic!("{}",error))
+    }
+/// Inverse modulo p. Zero gives `AlgebraError::DivisionByZero`, as in every [`Field`]. The modulus of
+/// a [`PrimeField`] built with `PrimeField(p)` is not checked, so a non-zero element can fail too, with
+/// `AlgebraError::NonInvertibleElement` carrying gcd(n,p).
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{PrimeField,MathError};
+/// use algebra::field::Field;
+/// use num_bigint::BigInt;
+/// let z15=PrimeField(BigInt::from(15));
+/// assert_eq!(z15.new(BigInt::from(7)).try_inverse(),Ok(z15.new(BigInt::from(13))));
+/// assert_eq!(z15.new(BigInt::from(6)).try_inverse(),Err(MathError::NonInvertibleElement(BigInt::from(3))));
+/// assert_eq!(z15.zero().try_inverse(),Err(MathError::DivisionByZero));
+/// ```
+    fn try_inverse(&self)->Result<Self,MathError> {
+        if self.n.is_zero() {return Err(MathError::DivisionByZero);}
+        if self.n.is_one() {return Ok(self.clone());}
+        let [u,_,d]=ring::gcdext(&self.n, &self.modulus.0);
+        if d.is_one() {Ok(Mod::new(u, self.modulus.clone()))} else {Err(MathError::NonInvertibleElement(d))}
+    }
+}
+impl Mod {
+/// Sum of two elements, or `AlgebraError::ModulusMismatch` if their moduli differ.
+    pub fn checked_add(&self,rhs:&Mod)->Result<Mod,MathError>{
+        if self.modulus!=rhs.modulus {return Err(MathError::ModulusMismatch);}
+        let mut sum=&self.n+&rhs.n;
+        if sum>=self.modulus.0 {sum-=&self.modulus.0;}
+        Ok(Mod { n: sum, modulus: self.modulus.clone() })
+    }
+/// Difference of two elements, or `AlgebraError::ModulusMismatch` if their moduli differ.
+    pub fn checked_sub(&self,rhs:&Mod)->Result<Mod,MathError>{
+        if self.modulus!=rhs.modulus {return Err(MathError::ModulusMismatch);}
+        let mut difference=&self.n-&rhs.n;
+        if difference<BigInt::zero() {difference+=&self.modulus.0;}
+        Ok(Mod { n: difference, modulus: self.modulus.clone() })
+    }
+/// Product of two elements, or `AlgebraError::ModulusMismatch` if their moduli differ.
+    pub fn checked_mul(&self,rhs:&Mod)->Result<Mod,MathError>{
+        if self.modulus!=rhs.modulus {return Err(MathError::ModulusMismatch);}
+        Ok(Mod { n: (&self.n*&rhs.n)%&self.modulus.0, modulus: self.modulus.clone() })
+    }
+/// Quotient of two elements, failing on different moduli or if rhs is not invertible.
+    pub fn checked_div(&self,rhs:&Mod)->Result<Mod,MathError>{
+        if self.modulus!=rhs.modulus {return Err(MathError::ModulusMismatch);}
+        self.checked_mul(&rhs.try_inverse()?)
+    }
 }
 impl Mod {
     pub fn to_string(&self)->String{
         format!("Mod({},{})",self.n,self.modulus.0.clone())
     }
-    pub fn new(mut n:BigInt,modulus:PrimeField)->Self{
-        let prime=modulus.0.clone();
-        while n.sign()==Minus {
-            n=n+&prime;
-        }
-        Mod { n: n%&prime, modulus: modulus }
+    pub fn new(n:BigInt,modulus:PrimeField)->Self{
+        Mod { n: BigInt::modulo(&n, &modulus.0), modulus }
     }
     
     
 /// This is Chinese Reminder Algorithm [10.52 H.Cohen Handbook of Elliptic and Hyperelliptic curves cryptography]
 /// Given a Vec of Mod (x1, x2,...,xn) with coprime modulus, this algorithm computes z in the same 
-/// residue classes as x1, x2,...,xn.
+/// residue classes as x1, x2,...,xn. The product of the moduli is composite, so z lives in Z/nZ.
 /// 
 /// # Example
 /// ```
-/// use crate::algebra::integers::IntUtilities;
 /// use crate::algebra::intmod::Mod;
 /// use algebra::intmod::PrimeField;
+/// use algebra::intmod::modn::{IntegerModRing,ModN};
 /// use num_bigint::BigInt;
 /// let f=Mod::new(BigInt::from(1),PrimeField(BigInt::from(3)));
 /// let g=Mod::new(BigInt::from(2),PrimeField(BigInt::from(5)));
@@ -253,25 +308,13 @@ impl Mod {
 /// let i=Mod::new(BigInt::from(5),PrimeField(BigInt::from(11)));
 /// let j=Mod::new(BigInt::from(9),PrimeField(BigInt::from(13)));
 /// let chinese=Mod::chinese(vec![&f,&g,&h,&i,&j]);
-/// let expected_mod=Some(Mod { n: BigInt::from(8992), modulus:PrimeField(BigInt::from(15015))});
+/// let expected_mod=Some(ModN { n: BigInt::from(8992), modulus:IntegerModRing(BigInt::from(15015))});
 /// assert_eq!(expected_mod,chinese);
 /// 
 /// ```
-    pub fn chinese(moduli:Vec<&Mod>)->Option<Mod>{
-        let mut m=moduli[0].modulus.0.clone();
-        let mut x=moduli[0].n.clone();
-        
-        for i in 1..moduli.len() {
-            let [u,v,d]=BigInt::gcdext(moduli[i].modulus.0.clone(), m.clone());
-            if d!=BigInt::one() {return None;} 
-            x=&u*&moduli[i].modulus.0*x+&v*&m*&moduli[i].n;
-            m=&m*&moduli[i].modulus.0;
-            x=x%&m;               }
-            Some(Mod::new(x, PrimeField(m)))
-    }
-    fn square(&mut self)->Self{
-        let sqr=self.n.modpow(&BigInt::from(2),&self.modulus.0);
-        Mod::new(sqr,self.modulus.clone())
+    pub fn chinese(moduli:Vec<&Mod>)->Option<ModN>{
+        let residues:Vec<ModN>=moduli.into_iter().map(|x|ModN::from(x.clone())).collect();
+        ModN::chinese(residues.iter().collect())
     }
 /// # Example
 /// ```
@@ -279,7 +322,7 @@ impl Mod {
 /// use crate::algebra::intmod::Mod;
 /// use algebra::intmod::PrimeField;
 /// use num_bigint::BigInt;
-/// let field=PrimeField(BigInt::from(13i16));
+/// let field=PrimeField::try_new(BigInt::from(13i16)).unwrap();
 /// let mut x=field.new(BigInt::from(7i16));
 /// let exp = BigInt::from(-55i16);
 /// let expected_power=field.new(BigInt::from(11));
@@ -287,64 +330,44 @@ impl Mod {
 /// 
 /// ```
     pub fn pow_mod(&mut self,exp:&BigInt)->Mod {
-       /*Montgomery ladder */
        if exp.sign()==Minus {
-        *self=self.inverse();
+           return pow_sliding_window(&self.inverse(), &-exp);
        }
-       let bin=BigInt::to_binary(&exp);
-       let mut p0=self.one();
-       let mut p1=self.clone();
-       for i in bin.iter() {
-           if i.is_zero() {
-               p1=&p1*&p0;
-               p0=p0.square();
-           }
-           else {
-               p0=p0*&p1;
-               p1=p1.square();
-               }
-       }
-       p0.clone()
+       pow_sliding_window(self, exp)
+    }
+/// Computes self^exp with the Montgomery ladder of [`pow_ladder`], over as many bits as the modulus,
+/// or as the exponent when it is longer: the sequence of multiplications does not depend on the
+/// exponent. Negative exponents are allowed for invertible elements.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z13=PrimeField::try_new(BigInt::from(13)).unwrap();
+/// let x=z13.new(BigInt::from(7));
+/// assert_eq!(x.pow_mod_ladder(&BigInt::from(-55)),x.clone().pow_mod(&BigInt::from(-55)));
+/// ```
+    pub fn pow_mod_ladder(&self,exp:&BigInt)->Mod {
+        let bits=exp.bits().max(self.modulus.0.bits());
+        if exp.sign()==Minus {
+            return pow_ladder(&self.inverse(), &-exp, bits);
+        }
+        pow_ladder(self, exp, bits)
     }
+/// Square root modulo p, or `MathError::QuadraticNonResidueModP`. The smaller of the two roots is returned.
+/// The algorithm depends on p: a single exponentiation for p = 3 mod 4, Atkin's formula for p = 5 mod 8,
+/// and for p = 1 mod 8 Tonelli–Shanks, or Cipolla when the 2-adic valuation of p-1 is large.
     pub fn check_sqrt_mod_prime(&mut self)->ModResult {
-        if self.is_zero(){return Ok(self.zero());}
-        let m=self.modulus.0.clone();
-        let even_part=BigInt::even_part(&(&m-BigInt::one()));
-        let q=(&m-BigInt::one())/even_part.0;
-        let mut n=loop { 
-            let rand = BigInt::random_8bit();
-            if BigInt::kroneker(rand.clone(), m.clone())==-1i8 {break Mod::new(rand,PrimeField(m.clone()));}
-        };
-        let z=n.pow_mod(&q);
-        let mut y = z;
-        let mut r=even_part.1;
-        let mut x= self.pow_mod(&((&q-BigInt::one())/BigInt::from(2)));
-        
-        let mut b=self.clone()*x.square();
-        x=&x*&self;
-        
-        while !b.is_one(){
-            let mut shift=1u64;
-            let mut pow_two= BigInt::from(2);
-        
-            while  !b.pow_mod(&pow_two).is_one(){
-                pow_two<<=1;
-                shift+=1;   
+        if self.is_zero() || self.modulus.0==BigInt::from(2) {return Ok(self.clone());}
+        let m=&self.modulus.0;
+        match u8::try_from(m%8u8).unwrap() {
+            3|7=>self.sqrt_3_mod_4(),
+            5=>self.sqrt_atkin(),
+            _=>{
+                let e=BigInt::even_part(&(m-BigInt::one())).1;
+                if e*e>8*m.bits() {self.sqrt_cipolla()} else {self.sqrt_tonelli_shanks()}
             }
-
-        
-        if shift==r {return Err(MathError::QuadraticNonResidueModP);}
-        
-        let exp=r-shift-1;
-        pow_two=BigInt::one()<<exp;
-        let mut t=y.pow_mod(&pow_two);
-        y=t.square();
-        r=shift;
-        x=&x*&t;
-        b=&b*&y;
-    }
-    let neg_x=x.clone().neg();
-    if x<neg_x {Ok(x)} else {Ok(-x)}
+        }
     }
 /// # Example
 /// ```
@@ -352,7 +375,7 @@ impl Mod {
 /// use crate::algebra::intmod::Mod;
 /// use algebra::intmod::PrimeField;
 /// use num_bigint::BigInt;
-/// let z17=PrimeField(BigInt::from(17));
+/// let z17=PrimeField::try_new(BigInt::from(17)).unwrap();
 /// let mut n=z17.new(BigInt::from(15));
 /// let expected_sqrt=z17.new(BigInt::from(7));
 /// assert_eq!(expected_sqrt,n.sqrt_mod_prime());
@@ -364,4 +387,52 @@ pub fn sqrt_mod_prime(&mut self)-> Mod{
         Err(error)=>panic!("{:?}",error)
     }
 }
+/// Multiplicative order of a non-zero element, that is the smallest k>0 with self^k=1.
+/// It is computed from the factorization of p-1 [Algorithm 1.4.3 H.Cohen A course in computational number theory].
+/// The output is None for zero.
+/// 
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z13=PrimeField::try_new(BigInt::from(13)).unwrap();
+/// assert_eq!(z13.new(BigInt::from(3)).multiplicative_order(),Some(BigInt::from(3)));
+/// assert_eq!(z13.new(BigInt::from(2)).multiplicative_order(),Some(BigInt::from(12)));
+/// assert_eq!(z13.zero().multiplicative_order(),None);
+/// ```
+pub fn multiplicative_order(&self)->Option<BigInt>{
+    self.multiplicative_order_with(&Factorization::new(&self.modulus.group_order()))
+}
+/// Same as [`Mod::multiplicative_order`], with the factorization of p-1 given by the caller.
+pub fn multiplicative_order_with(&self,group_order:&Factorization)->Option<BigInt>{
+    if self.is_zero() {return None;}
+    let mut order=group_order.value();
+    for (q,e) in &group_order.0 {
+        for _ in 0..*e {
+            let candidate=&order/q;
+            if self.clone().pow_mod(&candidate).is_one() {order=candidate;} else {break;}
+        }
+    }
+    Some(order)
+}
+/// Checks whether self generates the multiplicative group, that is self^((p-1)/q)!=1
+/// for every prime q dividing p-1.
+/// 
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z13=PrimeField::try_new(BigInt::from(13)).unwrap();
+/// assert!(z13.new(BigInt::from(2)).is_primitive_root());
+/// assert!(!z13.new(BigInt::from(3)).is_primitive_root());
+/// ```
+pub fn is_primitive_root(&self)->bool{
+    self.is_primitive_root_with(&Factorization::new(&self.modulus.group_order()))
+}
+/// Same as [`Mod::is_primitive_root`], with the factorization of p-1 given by the caller.
+pub fn is_primitive_root_with(&self,group_order:&Factorization)->bool{
+    if self.is_zero() {return false;}
+    let order=group_order.value();
+    group_order.0.iter().all(|(q,_)|!self.clone().pow_mod(&(&order/q)).is_one())
+}
 }
@@ -0,0 +1,448 @@
+//! Constant-time arithmetic in the prime field Z/pZ, for secret data.
+//! [`CtMod<L>`] stores an element in Montgomery form in exactly L 64-bit limbs, whatever its value,
+//! and every operation runs the same sequence of instructions and memory accesses for all inputs
+//! of a given [`CtField<L>`]: carries are propagated through whole limb arrays, conditional
+//! subtractions are replaced by masked selects [Algorithm 14.36 A.Menezes P.van Oorschot S.Vanstone
+//! Handbook of applied cryptography], and the inverse is x^(p-2) by Fermat's little theorem.
+//! Only the modulus is treated as public.
+//!
+//! The other prime field types ([`Mod`], [`MontgomeryMod`](super::montgomery::MontgomeryMod),
+//! [`Fp64`](super::fp64::Fp64)) leak the size of their operands through `BigInt` lengths, early exits
+//! and branches, and should not be used with secret keys. `cargo bench --bench dudect` checks
+//! this module for secret-dependent timing with dudect-style statistics
+//! [O.Reparaz J.Balasch I.Verbauwhede Dude, is my code constant time?, 2017].
+//! Equality, [`CtMod::select`] and [`CtMod::conditional_swap`] are constant time as well, the
+//! ordering and `Display` are not.
+//!
+//! # Example
+//! ```
+//! use algebra::intmod::{PrimeField, ct::CtField};
+//! use num_bigint::BigInt;
+//! use num_traits::One;
+//! // p = 2^255-19 fits in four limbs
+//! let p:BigInt=(BigInt::one()<<255)-BigInt::from(19);
+//! let field=PrimeField::try_new(p.clone()).unwrap();
+//! let ct=CtField::<4>::from_prime_field(&field);
+//! let secret=BigInt::from(0xdead_beef_u64);
+//! let x=ct.new(BigInt::from(9));
+//! assert_eq!(x.pow(&secret).to_mod(),field.new(BigInt::from(9)).pow_mod(&secret));
+//! ```
+use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt, cmp::Ordering, hint::black_box, sync::Arc};
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use crate::error::AlgebraError;
+use crate::field::Field;
+use crate::integers::IntUtilities;
+use super::{Mod, PrimeField};
+
+#[derive(Debug, PartialEq, Eq)]
+struct CtParams<const L:usize> {
+    modulus:[u64;L],
+    /// -p^-1 mod 2^64
+    n_prime:u64,
+    r_mod_p:[u64;L],
+    r2_mod_p:[u64;L],
+    /// p-2, the exponent of the inverse
+    inverse_exp:[u64;L]
+}
+/// The prime field Z/pZ for an odd prime p < 2^(64L), with elements of L limbs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CtField<const L:usize>(Arc<CtParams<L>>);
+impl<const L:usize> CtField<L> {
+/// Precomputes the Montgomery constants of the field. Returns [`AlgebraError::ModulusTooLarge`] if p
+/// does not fit in L limbs, or [`AlgebraError::NonPrimeModulus`] if p is even.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{PrimeField, ct::CtField};
+/// use algebra::error::AlgebraError;
+/// use num_bigint::BigInt;
+/// let field=PrimeField::try_new(BigInt::from(1_000_000_007)).unwrap();
+/// let ct=CtField::<1>::try_from_prime_field(&field).unwrap();
+/// assert_eq!(ct.new(BigInt::from(-1)).to_bigint(),BigInt::from(1_000_000_006));
+/// let large=PrimeField::try_new(BigInt::from(2).pow(127)-BigInt::from(1)).unwrap();
+/// assert_eq!(CtField::<1>::try_from_prime_field(&large),Err(AlgebraError::ModulusTooLarge(large.0)));
+/// ```
+    pub fn try_from_prime_field(field:&PrimeField)->Result<CtField<L>,AlgebraError>{
+        let modulus=field.0.clone();
+        if L==0 || modulus.bits()>64*L as u64 {return Err(AlgebraError::ModulusTooLarge(modulus));}
+        if BigInt::is_even(&modulus) {return Err(AlgebraError::NonPrimeModulus(modulus));}
+        let r=BigInt::one()<<(64*L);
+        let p=to_limbs::<L>(&modulus);
+        let params=CtParams {
+            modulus:p,
+            n_prime:n_prime(p[0]),
+            r_mod_p:to_limbs(&(&r%&modulus)),
+            r2_mod_p:to_limbs(&((&r*&r)%&modulus)),
+            inverse_exp:to_limbs(&(&modulus-BigInt::from(2)))
+        };
+        Ok(CtField(Arc::new(params)))
+    }
+/// As [`CtField::try_from_prime_field`], panicking on an even or too large modulus.
+    pub fn from_prime_field(field:&PrimeField)->CtField<L>{
+        CtField::try_from_prime_field(field).unwrap_or_else(|error|panic!("{}",error))
+    }
+    pub fn modulus(&self)->BigInt{
+        from_limbs(&self.0.modulus)
+    }
+    pub fn prime_field(&self)->PrimeField{
+        PrimeField::new_unchecked(self.modulus())
+    }
+/// The element n mod p. The reduction of n is done with `BigInt` arithmetic, so n should already
+/// be reduced if its size is secret.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(&self,n:BigInt)->CtMod<L>{
+        let n=BigInt::modulo(&n, &self.modulus());
+        self.from_limbs(to_limbs(&n))
+    }
+/// The element with the given little-endian limbs, which must be smaller than p.
+    pub fn from_limbs(&self,limbs:[u64;L])->CtMod<L>{
+        CtMod { limbs: self.mont_mul(&limbs, &self.0.r2_mod_p), field: self.clone() }
+    }
+    pub fn zero(&self)->CtMod<L>{
+        CtMod { limbs: [0;L], field: self.clone() }
+    }
+    pub fn one(&self)->CtMod<L>{
+        CtMod { limbs: self.0.r_mod_p, field: self.clone() }
+    }
+/// A uniformly random element.
+    pub fn random(&self)->CtMod<L>{
+        self.new(self.prime_field().random().n)
+    }
+/// Montgomery multiplication abR^-1 mod p with R=2^(64L), in the coarsely integrated operand scanning
+/// form: the partial result t < 2p is kept in L limbs and two carry words.
+    fn mont_mul(&self,a:&[u64;L],b:&[u64;L])->[u64;L]{
+        let params=&self.0;
+        let p=&params.modulus;
+        let mut t=[0u64;L];
+        let mut t_high=0u64;
+        for &b_i in b.iter() {
+            let mut carry=0;
+            for j in 0..L {
+                (t[j],carry)=mac(t[j], a[j], b_i, carry);
+            }
+            let (high,top)=adc(t_high, carry, 0);
+            // t+m*p is divisible by 2^64
+            let m=t[0].wrapping_mul(params.n_prime);
+            let (_,mut carry)=mac(t[0], m, p[0], 0);
+            for j in 1..L {
+                (t[j-1],carry)=mac(t[j], m, p[j], carry);
+            }
+            let (last,c)=adc(high, carry, 0);
+            t[L-1]=last;
+            t_high=top+c;
+        }
+        let (reduced,borrow)=sub_limbs(&t, p);
+        select_limbs(&t, &reduced, mask(t_high | (borrow^1)))
+    }
+    fn add(&self,a:&[u64;L],b:&[u64;L])->[u64;L]{
+        let (sum,carry)=add_limbs(a, b);
+        let (reduced,borrow)=sub_limbs(&sum, &self.0.modulus);
+        select_limbs(&sum, &reduced, mask(carry | (borrow^1)))
+    }
+    fn sub(&self,a:&[u64;L],b:&[u64;L])->[u64;L]{
+        let (difference,borrow)=sub_limbs(a, b);
+        let correction=select_limbs(&[0;L], &self.0.modulus, mask(borrow));
+        add_limbs(&difference, &correction).0
+    }
+}
+impl<const L:usize> From<&PrimeField> for CtField<L> {
+    fn from(field: &PrimeField) -> Self {
+        CtField::from_prime_field(field)
+    }
+}
+/// An element of a [`CtField<L>`], in Montgomery form.
+#[derive(Debug, Clone)]
+pub struct CtMod<const L:usize> {
+    limbs:[u64;L],
+    field:CtField<L>
+}
+impl<const L:usize> CtMod<L> {
+/// Converts an element of Z/pZ to the constant-time representation in the given field.
+    pub fn from_mod(x:&Mod,field:&CtField<L>)->CtMod<L>{
+        if x.modulus.0!=field.modulus() {panic!("Elements of different fields!")}
+        field.new(x.n.clone())
+    }
+/// The little-endian limbs of the standard representative in [0,p).
+    pub fn to_limbs(&self)->[u64;L]{
+        let mut one=[0;L];
+        one[0]=1;
+        self.field.mont_mul(&self.limbs, &one)
+    }
+/// The standard representative in [0,p).
+    pub fn to_bigint(&self)->BigInt{
+        from_limbs(&self.to_limbs())
+    }
+    pub fn to_mod(&self)->Mod{
+        Mod::new(self.to_bigint(),self.field.prime_field())
+    }
+    pub fn field(&self)->&CtField<L>{
+        &self.field
+    }
+/// Returns b if choice is true and a otherwise, without branching on choice.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{PrimeField, ct::{CtField, CtMod}};
+/// use num_bigint::BigInt;
+/// let ct=CtField::<1>::from_prime_field(&PrimeField(BigInt::from(101)));
+/// let (a,b)=(ct.new(BigInt::from(3)),ct.new(BigInt::from(5)));
+/// assert_eq!(CtMod::select(&a,&b,true),b);
+/// assert_eq!(CtMod::select(&a,&b,false),a);
+/// ```
+    pub fn select(a:&CtMod<L>,b:&CtMod<L>,choice:bool)->CtMod<L>{
+        a.check_field(b);
+        CtMod { limbs: select_limbs(&a.limbs, &b.limbs, mask(choice as u64)), field: a.field.clone() }
+    }
+/// Swaps a and b if choice is true, without branching on choice.
+    pub fn conditional_swap(a:&mut CtMod<L>,b:&mut CtMod<L>,choice:bool){
+        a.check_field(b);
+        let mask=mask(choice as u64);
+        for j in 0..L {
+            let t=mask & (a.limbs[j]^b.limbs[j]);
+            a.limbs[j]^=t;
+            b.limbs[j]^=t;
+        }
+    }
+/// Constant-time equality: every limb is compared.
+    pub fn ct_eq(&self,other:&CtMod<L>)->bool{
+        self.check_field(other);
+        let difference=self.limbs.iter().zip(&other.limbs).fold(0,|d,(a,b)|d | (a^b));
+        is_zero_word(difference)
+    }
+    pub fn ct_is_zero(&self)->bool{
+        is_zero_word(self.limbs.iter().fold(0,|d,a|d | a))
+    }
+/// Computes self^exp with a Montgomery ladder over all 64L bits, one product and one squaring per bit
+/// whatever the exponent [M.Joye S.Yen The Montgomery powering ladder, 2002]. The exponent must lie
+/// in [0,2^(64L)); a negative exponent inverts the base first. Converting a `BigInt` reveals its
+/// sign and length, secret exponents should be given to [`CtMod::pow_limbs`].
+    pub fn pow(&self,exp:&BigInt)->CtMod<L>{
+        if exp.is_negative() {return self.inverse().pow(&-exp);}
+        if exp.bits()>64*L as u64 {panic!("The exponent {} does not fit in {} limbs",exp,L)}
+        self.pow_limbs(&to_limbs(exp))
+    }
+/// Computes self^exp for an exponent given by its little-endian limbs, in constant time.
+    pub fn pow_limbs(&self,exp:&[u64;L])->CtMod<L>{
+        let mut r0=self.field.one().limbs;
+        let mut r1=self.limbs;
+        for i in (0..64*L).rev() {
+            let bit=mask((exp[i/64]>>(i%64)) & 1);
+            // with r0,r1 swapped when the bit is set, the ladder step is always r1=r0*r1, r0=r0^2
+            cswap_limbs(&mut r0, &mut r1, bit);
+            r1=self.field.mont_mul(&r0, &r1);
+            r0=self.field.mont_mul(&r0, &r0);
+            cswap_limbs(&mut r0, &mut r1, bit);
+        }
+        CtMod { limbs: r0, field: self.field.clone() }
+    }
+    fn check_field(&self,other:&CtMod<L>){
+        if self.field!=other.field {panic!("Elements of different fields!")}
+    }
+}
+impl<const L:usize> From<CtMod<L>> for Mod {
+    fn from(x: CtMod<L>) -> Self {
+        x.to_mod()
+    }
+}
+fn adc(a:u64,b:u64,carry:u64)->(u64,u64){
+    let t=a as u128+b as u128+carry as u128;
+    (t as u64,(t>>64) as u64)
+}
+fn sbb(a:u64,b:u64,borrow:u64)->(u64,u64){
+    let t=(a as u128).wrapping_sub(b as u128+borrow as u128);
+    (t as u64,(t>>127) as u64)
+}
+/// a+b*c+carry, which fits in 128 bits.
+fn mac(a:u64,b:u64,c:u64,carry:u64)->(u64,u64){
+    let t=a as u128+b as u128*c as u128+carry as u128;
+    (t as u64,(t>>64) as u64)
+}
+/// All ones if bit is 1, zero if bit is 0. The optimizer must not see that the result has only two
+/// values, or it may turn the selects that use it back into branches.
+fn mask(bit:u64)->u64{
+    black_box(0u64.wrapping_sub(bit))
+}
+fn is_zero_word(x:u64)->bool{
+    // the top bit of x|-x is set exactly when x is not zero
+    ((x | x.wrapping_neg())>>63)==0
+}
+fn add_limbs<const L:usize>(a:&[u64;L],b:&[u64;L])->([u64;L],u64){
+    let mut sum=[0;L];
+    let mut carry=0;
+    for j in 0..L {
+        (sum[j],carry)=adc(a[j], b[j], carry);
+    }
+    (sum,carry)
+}
+fn sub_limbs<const L:usize>(a:&[u64;L],b:&[u64;L])->([u64;L],u64){
+    let mut difference=[0;L];
+    let mut borrow=0;
+    for j in 0..L {
+        (difference[j],borrow)=sbb(a[j], b[j], borrow);
+    }
+    (difference,borrow)
+}
+fn select_limbs<const L:usize>(a:&[u64;L],b:&[u64;L],mask:u64)->[u64;L]{
+    let mut result=[0;L];
+    for j in 0..L {
+        result[j]=a[j]^(mask & (a[j]^b[j]));
+    }
+    result
+}
+fn cswap_limbs<const L:usize>(a:&mut [u64;L],b:&mut [u64;L],mask:u64){
+    for j in 0..L {
+        let t=mask & (a[j]^b[j]);
+        a[j]^=t;
+        b[j]^=t;
+    }
+}
+/// -p^-1 mod 2^64 by Newton iteration, each step doubling the number of correct bits.
+fn n_prime(p:u64)->u64{
+    let mut inverse=1u64;
+    for _ in 0..6 {
+        inverse=inverse.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inverse)));
+    }
+    inverse.wrapping_neg()
+}
+fn to_limbs<const L:usize>(n:&BigInt)->[u64;L]{
+    let mut limbs=[0;L];
+    for (j,digit) in n.to_biguint().unwrap().iter_u64_digits().take(L).enumerate() {
+        limbs[j]=digit;
+    }
+    limbs
+}
+fn from_limbs<const L:usize>(limbs:&[u64;L])->BigInt{
+    limbs.iter().rev().fold(BigInt::zero(),|n,&digit|(n<<64)+BigInt::from(digit))
+}
+impl<const L:usize> fmt::Display for CtMod<L> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+/// Constant-time equality within a field, see [`CtMod::ct_eq`].
+impl<const L:usize> PartialEq for CtMod<L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.field==other.field && self.ct_eq(other)
+    }
+}
+impl<const L:usize> Eq for CtMod<L> {}
+impl<const L:usize> PartialOrd for CtMod<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// Compares the standard representatives in [0,p) first and the moduli second, the order [`Mod`]
+/// derives. Not constant time.
+impl<const L:usize> Ord for CtMod<L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.to_bigint(),self.field.modulus()).cmp(&(other.to_bigint(),other.field.modulus()))
+    }
+}
+impl<'a,const L:usize> Add<&'a CtMod<L>> for &'a CtMod<L> {
+    type Output = CtMod<L>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_field(rhs);
+        CtMod { limbs: self.field.add(&self.limbs, &rhs.limbs), field: self.field.clone() }
+    }
+}
+impl<'a,const L:usize> Sub<&'a CtMod<L>> for &'a CtMod<L> {
+    type Output = CtMod<L>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_field(rhs);
+        CtMod { limbs: self.field.sub(&self.limbs, &rhs.limbs), field: self.field.clone() }
+    }
+}
+impl<'a,const L:usize> Mul<&'a CtMod<L>> for &'a CtMod<L> {
+    type Output = CtMod<L>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_field(rhs);
+        CtMod { limbs: self.field.mont_mul(&self.limbs, &rhs.limbs), field: self.field.clone() }
+    }
+}
+impl<'a,const L:usize> Div<&'a CtMod<L>> for &'a CtMod<L> {
+    type Output = CtMod<L>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self*&rhs.inverse()
+    }
+}
+impl<const L:usize> Neg for &CtMod<L> {
+    type Output = CtMod<L>;
+    fn neg(self) -> Self::Output {
+        CtMod { limbs: self.field.sub(&[0;L], &self.limbs), field: self.field.clone() }
+    }
+}
+impl<const L:usize> Add<CtMod<L>> for CtMod<L> {
+    type Output = CtMod<L>;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self+&rhs
+    }
+}
+impl<'a,const L:usize> Add<&'a CtMod<L>> for CtMod<L> {
+    type Output = CtMod<L>;
+    fn add(self, rhs: &'a CtMod<L>) -> Self::Output {
+        &self+rhs
+    }
+}
+impl<'a,const L:usize> Add<&'a mut CtMod<L>> for CtMod<L> {
+    type Output = CtMod<L>;
+    fn add(self, rhs: &'a mut CtMod<L>) -> Self::Output {
+        &self+&*rhs
+    }
+}
+impl<const L:usize> Sub<CtMod<L>> for CtMod<L> {
+    type Output = CtMod<L>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self-&rhs
+    }
+}
+impl<const L:usize> Mul<CtMod<L>> for CtMod<L> {
+    type Output = CtMod<L>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self*&rhs
+    }
+}
+impl<'a,const L:usize> Mul<&'a CtMod<L>> for CtMod<L> {
+    type Output = CtMod<L>;
+    fn mul(self, rhs: &'a CtMod<L>) -> Self::Output {
+        &self*rhs
+    }
+}
+impl<const L:usize> Div<CtMod<L>> for CtMod<L> {
+    type Output = CtMod<L>;
+    fn div(self, rhs: Self) -> Self::Output {
+        &self/&rhs
+    }
+}
+impl<const L:usize> Neg for CtMod<L> {
+    type Output = CtMod<L>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+/// The inverse is computed in constant time as x^(p-2). Only the final check that the element
+/// was not zero branches, so the `AlgebraError::DivisionByZero` of `try_inverse`, and the panic of
+/// `inverse`, reveal nothing else.
+impl<const L:usize> Field for CtMod<L> {
+    fn one(&self)->Self {
+        self.field.one()
+    }
+    fn zero(&self)->Self {
+        self.field.zero()
+    }
+    fn is_zero(&self)->bool {
+        self.ct_is_zero()
+    }
+    fn is_one(&self)->bool {
+        self.ct_eq(&self.field.one())
+    }
+    fn inverse(&self)->Self {
+        self.try_inverse().unwrap_or_else(|error|panic!("{}",error))
+    }
+    fn try_inverse(&self)->Result<Self,AlgebraError> {
+        let inverse=self.pow_limbs(&self.field.0.inverse_exp);
+        if inverse.ct_is_zero() {return Err(AlgebraError::DivisionByZero);}
+        Ok(inverse)
+    }
+}
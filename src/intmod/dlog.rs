@@ -0,0 +1,219 @@
+//! Discrete logarithms in the multiplicative group of a prime field.
+//! The main entry point is [`Mod::discrete_log`], which reduces the problem to subgroups of
+//! prime order with the Pohlig–Hellman decomposition and solves each of them with
+//! baby-step giant-step (small primes) or Pollard rho for logarithms (large primes).
+//! See [H.Cohen A course in computational number theory, section 5.1.2] and
+//! [A.Menezes P.van Oorschot S.Vanstone Handbook of applied cryptography, section 3.6].
+use std::collections::HashMap;
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::{One, Zero, ToPrimitive};
+use crate::integers::{IntUtilities, Factorization};
+use crate::field::Field;
+use super::{Mod, MathError};
+use super::modn::{IntegerModRing, ModN};
+
+/// Prime order subgroups up to this many bits are handled by baby-step giant-step,
+/// larger ones by Pollard rho.
+const BSGS_BITS:u64=32;
+impl Mod {
+/// Solves base^x = target, returning the least non-negative x, or None if target
+/// is not a power of base. The order of base is found from the factorization of p-1,
+/// then [`pohlig_hellman`] does the rest.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{Mod,PrimeField};
+/// use num_bigint::BigInt;
+/// let z1019=PrimeField::try_new(BigInt::from(1019)).unwrap();
+/// let base=z1019.new(BigInt::from(2));
+/// let target=z1019.new(BigInt::from(5));
+/// let x=Mod::discrete_log(&base,&target).unwrap();
+/// assert_eq!(x,BigInt::from(10));
+/// assert_eq!(Mod::discrete_log(&z1019.new(BigInt::from(4)),&z1019.new(BigInt::from(2))),None);
+/// ```
+    pub fn discrete_log(base:&Mod,target:&Mod)->Option<BigInt>{
+        Mod::try_discrete_log(base, target).unwrap_or_else(|error|panic!("{}",error))
+    }
+/// As [`Mod::discrete_log`], or `MathError::ModulusMismatch` if base and target belong to different
+/// fields.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{Mod,PrimeField,MathError};
+/// use num_bigint::BigInt;
+/// let z1019=PrimeField::try_new(BigInt::from(1019)).unwrap();
+/// let z1021=PrimeField::try_new(BigInt::from(1021)).unwrap();
+/// let base=z1019.new(BigInt::from(2));
+/// assert_eq!(Mod::try_discrete_log(&base,&z1019.new(BigInt::from(5))),Ok(Some(BigInt::from(10))));
+/// assert_eq!(Mod::try_discrete_log(&base,&z1021.new(BigInt::from(5))),Err(MathError::ModulusMismatch));
+/// ```
+    pub fn try_discrete_log(base:&Mod,target:&Mod)->Result<Option<BigInt>,MathError>{
+        if base.modulus!=target.modulus {return Err(MathError::ModulusMismatch);}
+        let group_order=Factorization::new(&base.modulus.group_order());
+        let Some(order)=base.multiplicative_order_with(&group_order) else {return Ok(None)};
+        let order_factorization=Factorization(group_order.0.iter()
+            .map(|(q,_)|(q.clone(),multiplicity(&order,q)))
+            .filter(|(_,e)|*e>0)
+            .collect());
+        try_pohlig_hellman(base, target, &order_factorization)
+    }
+}
+fn multiplicity(n:&BigInt,q:&BigInt)->u32{
+    let mut n=n.clone();
+    let mut e=0;
+    while (&n%q).is_zero() {n/=q; e+=1;}
+    e
+}
+/// Pohlig–Hellman decomposition: given the factorization of the order of base, solves
+/// base^x = target one prime power at a time and recombines the exponents with
+/// the Chinese remainder theorem. The output is None if target is not a power of base.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{dlog::pohlig_hellman,PrimeField};
+/// use algebra::integers::Factorization;
+/// use num_bigint::BigInt;
+/// let z8101=PrimeField::try_new(BigInt::from(8101)).unwrap();
+/// let base=z8101.new(BigInt::from(6));
+/// let target=z8101.new(BigInt::from(7531));
+/// let order=Factorization::new(&BigInt::from(8100));
+/// assert_eq!(pohlig_hellman(&base,&target,&order),Some(BigInt::from(6689)));
+/// ```
+pub fn pohlig_hellman(base:&Mod,target:&Mod,order:&Factorization)->Option<BigInt>{
+    try_pohlig_hellman(base, target, order).unwrap_or_else(|error|panic!("{}",error))
+}
+/// As [`pohlig_hellman`], or `MathError::ModulusMismatch` if base and target belong to different
+/// fields.
+pub fn try_pohlig_hellman(base:&Mod,target:&Mod,order:&Factorization)->Result<Option<BigInt>,MathError>{
+    if base.modulus!=target.modulus {return Err(MathError::ModulusMismatch);}
+    Ok(pohlig_hellman_unchecked(base, target, order))
+}
+fn pohlig_hellman_unchecked(base:&Mod,target:&Mod,order:&Factorization)->Option<BigInt>{
+    let n=order.value();
+    if !target.clone().pow_mod(&n).is_one() || target.is_zero() {return None;}
+    let mut residues=Vec::new();
+    for (q,e) in &order.0 {
+        let cofactor=&n/q.pow(*e);
+        let g=base.clone().pow_mod(&cofactor);
+        let h=target.clone().pow_mod(&cofactor);
+        residues.push(ModN::new(prime_power_log(&g, &h, q, *e)?, IntegerModRing(q.pow(*e))));
+    }
+    if residues.is_empty() {return Some(BigInt::zero());}
+    let x=ModN::chinese(residues.iter().collect())?.n;
+    if base.clone().pow_mod(&x)==*target {Some(x)} else {None}
+}
+/// Logarithm of h in base g, where g has order q^e with q prime.
+fn prime_power_log(g:&Mod,h:&Mod,q:&BigInt,e:u32)->Option<BigInt>{
+    let gamma=g.clone().pow_mod(&q.pow(e-1));
+    let g_inverse=g.inverse();
+    let mut x=BigInt::zero();
+    let mut q_k=BigInt::one();
+    for k in 0..e {
+        let shifted=&g_inverse.clone().pow_mod(&x)*h;
+        let h_k=shifted.clone().pow_mod(&q.pow(e-1-k));
+        let d_k=if q.bits()<=BSGS_BITS {baby_step_giant_step_unchecked(&gamma, &h_k, q)?} else {pollard_rho_log_unchecked(&gamma, &h_k, q)?};
+        x+=d_k*&q_k;
+        q_k*=q;
+    }
+    Some(x)
+}
+/// Baby-step giant-step: finds x in [0,order) with base^x = target in O(sqrt(order))
+/// time and memory, where order is any multiple of the order of base.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{dlog::baby_step_giant_step,PrimeField};
+/// use num_bigint::BigInt;
+/// let z1019=PrimeField::try_new(BigInt::from(1019)).unwrap();
+/// let base=z1019.new(BigInt::from(2));
+/// let target=z1019.new(BigInt::from(5));
+/// assert_eq!(baby_step_giant_step(&base,&target,&BigInt::from(1018)),Some(BigInt::from(10)));
+/// ```
+pub fn baby_step_giant_step(base:&Mod,target:&Mod,order:&BigInt)->Option<BigInt>{
+    try_baby_step_giant_step(base, target, order).unwrap_or_else(|error|panic!("{}",error))
+}
+/// As [`baby_step_giant_step`], or `MathError::ModulusMismatch` if base and target belong to
+/// different fields.
+pub fn try_baby_step_giant_step(base:&Mod,target:&Mod,order:&BigInt)->Result<Option<BigInt>,MathError>{
+    if base.modulus!=target.modulus {return Err(MathError::ModulusMismatch);}
+    Ok(baby_step_giant_step_unchecked(base, target, order))
+}
+fn baby_step_giant_step_unchecked(base:&Mod,target:&Mod,order:&BigInt)->Option<BigInt>{
+    let m=order.sqrt()+BigInt::one();
+    let mut baby_steps=HashMap::new();
+    let mut power=base.modulus.one();
+    let mut j=BigInt::zero();
+    while j<m {
+        baby_steps.entry(power.n.clone()).or_insert(j.clone());
+        power=&power*base;
+        j+=1;
+    }
+    // power is now base^m
+    let giant_step=power.inverse();
+    let mut gamma=target.clone();
+    let mut i=BigInt::zero();
+    while i<m {
+        if let Some(j)=baby_steps.get(&gamma.n) {
+            return Some((&i*&m+j)%order);
+        }
+        gamma=&gamma*&giant_step;
+        i+=1;
+    }
+    None
+}
+/// Pollard rho for logarithms: finds x with base^x = target, where base has prime
+/// order q. It uses the usual partition of the group in three sets and Floyd's cycle
+/// detection, restarting from a random point when the collision is useless.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{dlog::pollard_rho_log,PrimeField};
+/// use num_bigint::BigInt;
+/// // 4 has order 1019 modulo the safe prime 2039 = 2*1019+1
+/// let z2039=PrimeField::try_new(BigInt::from(2039)).unwrap();
+/// let base=z2039.new(BigInt::from(4));
+/// let target=z2039.new(BigInt::from(4)).pow_mod(&BigInt::from(777));
+/// assert_eq!(pollard_rho_log(&base,&target,&BigInt::from(1019)),Some(BigInt::from(777)));
+/// ```
+pub fn pollard_rho_log(base:&Mod,target:&Mod,order:&BigInt)->Option<BigInt>{
+    try_pollard_rho_log(base, target, order).unwrap_or_else(|error|panic!("{}",error))
+}
+/// As [`pollard_rho_log`], or `MathError::ModulusMismatch` if base and target belong to different
+/// fields.
+pub fn try_pollard_rho_log(base:&Mod,target:&Mod,order:&BigInt)->Result<Option<BigInt>,MathError>{
+    if base.modulus!=target.modulus {return Err(MathError::ModulusMismatch);}
+    Ok(pollard_rho_log_unchecked(base, target, order))
+}
+fn pollard_rho_log_unchecked(base:&Mod,target:&Mod,order:&BigInt)->Option<BigInt>{
+    if target.is_one() {return Some(BigInt::zero());}
+    if !target.clone().pow_mod(order).is_one() {return None;}
+    let mut rng=rand::thread_rng();
+    for _ in 0..32 {
+        let a0=rng.gen_bigint_range(&BigInt::zero(), order);
+        let b0=rng.gen_bigint_range(&BigInt::zero(), order);
+        let start=&base.clone().pow_mod(&a0)*&target.clone().pow_mod(&b0);
+        let mut tortoise=(start.clone(),a0.clone(),b0.clone());
+        let mut hare=(start,a0,b0);
+        loop {
+            rho_step(&mut tortoise, base, target, order);
+            rho_step(&mut hare, base, target, order);
+            rho_step(&mut hare, base, target, order);
+            if tortoise.0==hare.0 {break;}
+        }
+        // base^a1 target^b1 = base^a2 target^b2, so x (b2-b1) = a1-a2 mod order
+        let db=BigInt::modulo(&(&hare.2-&tortoise.2), order);
+        if db.is_zero() {continue;}
+        let da=BigInt::modulo(&(&tortoise.1-&hare.1), order);
+        let x=da*db.modpow(&(order-BigInt::from(2)), order)%order;
+        if base.clone().pow_mod(&x)==*target {return Some(x);}
+    }
+    None
+}
+fn rho_step(point:&mut (Mod,BigInt,BigInt),base:&Mod,target:&Mod,order:&BigInt){
+    let (x,a,b)=point;
+    match (&x.n%3u32).to_u32().unwrap() {
+        0=>{*x=&*x*&*x; *a=(&*a*2u32)%order; *b=(&*b*2u32)%order;},
+        1=>{*x=&*x*base; *a=(&*a+1u32)%order;},
+        _=>{*x=&*x*target; *b=(&*b+1u32)%order;}
+    }
+}
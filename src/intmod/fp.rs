@@ -0,0 +1,260 @@
+//! Prime fields whose modulus is part of the type.
+//! [`Fp<P>`] is the field Z/PZ for a prime P < 2^63 given as a const generic parameter: elements are a
+//! single `u64` in Montgomery form, the constants of the representation are computed at compile time,
+//! and arithmetic between different fields does not type check, instead of panicking at runtime.
+//! The modulus is checked to be a prime below 2^63 when the type is first used, with a deterministic
+//! Miller–Rabin test evaluated by the compiler, so `Fp<91>` fails to build.
+//!
+//! ```compile_fail
+//! use algebra::intmod::fp::Fp;
+//! let x=Fp::<13>::new(2);
+//! let y=Fp::<17>::new(2);
+//! let _=x+y;
+//! ```
+//! ```compile_fail
+//! use algebra::intmod::fp::Fp;
+//! let _=Fp::<91>::new(2);
+//! ```
+use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt, cmp::Ordering};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use rand::Rng;
+use crate::field::Field;
+use crate::integers::IntUtilities;
+use super::{Mod, PrimeField};
+use super::fp64::{montgomery_constants, montgomery_reduce, Fp64, Fp64Field, FP64_MODULUS_BOUND};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp<const P:u64>(u64);
+impl<const P:u64> Fp<P> {
+    const MODULUS_IS_PRIME:()=assert!(P<FP64_MODULUS_BOUND && is_prime_u64(P),"the modulus of Fp must be a prime below 2^63");
+    const N_PRIME:u64=montgomery_constants(P).0;
+    const R_MOD_P:u64=montgomery_constants(P).1;
+    const R2_MOD_P:u64=montgomery_constants(P).2;
+/// The element n mod P.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::fp::Fp;
+/// type F13=Fp<13>;
+/// let x=F13::new(12);
+/// let y=F13::new(3);
+/// assert_eq!(x+y,F13::new(2));
+/// assert_eq!(x*y,F13::new(10));
+/// assert_eq!(x/y,F13::new(4));
+/// ```
+    pub fn new(n:u64)->Fp<P>{
+        #[allow(clippy::let_unit_value)]
+        let ()=Self::MODULUS_IS_PRIME;
+        Fp(montgomery_reduce((n%P) as u128*Self::R2_MOD_P as u128, P, Self::N_PRIME))
+    }
+/// The element n mod P, for any integer n.
+    pub fn from_bigint(n:&BigInt)->Fp<P>{
+        Fp::new(BigInt::modulo(n, &BigInt::from(P)).to_u64().unwrap())
+    }
+    pub fn modulus()->u64{
+        P
+    }
+    pub fn prime_field()->PrimeField{
+        PrimeField::new_unchecked(BigInt::from(P))
+    }
+    pub fn zero()->Fp<P>{
+        Fp::new(0)
+    }
+    pub fn one()->Fp<P>{
+        Fp::new(1)
+    }
+    pub fn random()->Fp<P>{
+        Fp::new(rand::thread_rng().gen_range(0..P))
+    }
+/// Converts an element of Z/PZ, panicking if its modulus is not P.
+    pub fn from_mod(x:&Mod)->Fp<P>{
+        if x.modulus.0!=BigInt::from(P) {panic!("Elements of different fields!")}
+        Fp::from_bigint(&x.n)
+    }
+/// The standard representative in [0,P).
+    pub fn value(&self)->u64{
+        montgomery_reduce(self.0 as u128, P, Self::N_PRIME)
+    }
+    pub fn to_mod(&self)->Mod{
+        Mod::new(BigInt::from(self.value()),Self::prime_field())
+    }
+    pub fn to_fp64(&self)->Fp64{
+        Fp64Field::new_unchecked(P).new(self.value())
+    }
+/// Computes self^exp by left-to-right square and multiply.
+    pub fn pow(&self,exp:u64)->Fp<P>{
+        let mut result=Fp::one();
+        for i in (0..u64::BITS-exp.leading_zeros()).rev() {
+            result=result*result;
+            if (exp>>i)&1==1 {result=result*self;}
+        }
+        result
+    }
+}
+/// Deterministic Miller–Rabin test: the first twelve primes are enough as bases below 3.18*10^23
+/// [J.Sorenson J.Webster Strong pseudoprimes to twelve prime bases, 2017].
+const fn is_prime_u64(n:u64)->bool{
+    const BASES:[u64;12]=[2,3,5,7,11,13,17,19,23,29,31,37];
+    if n<2 {return false;}
+    let mut i=0;
+    while i<BASES.len() {
+        if n==BASES[i] {return true;}
+        if n.is_multiple_of(BASES[i]) {return false;}
+        i+=1;
+    }
+    let mut d=n-1;
+    let mut s=0;
+    while d.is_multiple_of(2) {d/=2; s+=1;}
+    let mut i=0;
+    while i<BASES.len() {
+        let mut x=pow_mod_u64(BASES[i], d, n);
+        if x!=1 && x!=n-1 {
+            let mut j=1;
+            while j<s && x!=n-1 {
+                x=(x as u128*x as u128%n as u128) as u64;
+                j+=1;
+            }
+            if x!=n-1 {return false;}
+        }
+        i+=1;
+    }
+    true
+}
+const fn pow_mod_u64(base:u64,mut exp:u64,n:u64)->u64{
+    let mut result=1u64;
+    let mut base=base%n;
+    while exp>0 {
+        if exp&1==1 {result=(result as u128*base as u128%n as u128) as u64;}
+        base=(base as u128*base as u128%n as u128) as u64;
+        exp>>=1;
+    }
+    result
+}
+impl<const P:u64> From<Fp<P>> for Mod {
+    fn from(x: Fp<P>) -> Self {
+        x.to_mod()
+    }
+}
+impl<const P:u64> fmt::Display for Fp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+impl<const P:u64> PartialOrd for Fp<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// By value in [0,P). Elements of different fields have different types, so the modulus needs no comparison.
+impl<const P:u64> Ord for Fp<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+impl<const P:u64> Add<Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    fn add(self, rhs: Self) -> Self::Output {
+        // both values are below 2^63, the sum does not overflow
+        let value=self.0+rhs.0;
+        Fp(if value>=P {value-P} else {value})
+    }
+}
+impl<const P:u64> Sub<Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fp(if self.0>=rhs.0 {self.0-rhs.0} else {self.0+P-rhs.0})
+    }
+}
+impl<const P:u64> Mul<Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Fp(montgomery_reduce(self.0 as u128*rhs.0 as u128, P, Self::N_PRIME))
+    }
+}
+impl<const P:u64> Div<Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self*rhs.inverse()
+    }
+}
+impl<const P:u64> Neg for Fp<P> {
+    type Output = Fp<P>;
+    fn neg(self) -> Self::Output {
+        if self.0==0 {self} else {Fp(P-self.0)}
+    }
+}
+impl<'a,const P:u64> Add<&'a Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    fn add(self, rhs: &'a Fp<P>) -> Self::Output {
+        self+*rhs
+    }
+}
+impl<'a,const P:u64> Add<&'a mut Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    fn add(self, rhs: &'a mut Fp<P>) -> Self::Output {
+        self+*rhs
+    }
+}
+impl<'a,const P:u64> Add<&'a Fp<P>> for &'a Fp<P> {
+    type Output = Fp<P>;
+    fn add(self, rhs: Self) -> Self::Output {
+        *self+*rhs
+    }
+}
+impl<'a,const P:u64> Sub<&'a Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    fn sub(self, rhs: &'a Fp<P>) -> Self::Output {
+        self-*rhs
+    }
+}
+impl<'a,const P:u64> Sub<&'a Fp<P>> for &'a Fp<P> {
+    type Output = Fp<P>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self-*rhs
+    }
+}
+impl<'a,const P:u64> Mul<&'a Fp<P>> for Fp<P> {
+    type Output = Fp<P>;
+    fn mul(self, rhs: &'a Fp<P>) -> Self::Output {
+        self**rhs
+    }
+}
+impl<'a,const P:u64> Mul<&'a Fp<P>> for &'a Fp<P> {
+    type Output = Fp<P>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        *self**rhs
+    }
+}
+impl<'a,const P:u64> Div<&'a Fp<P>> for &'a Fp<P> {
+    type Output = Fp<P>;
+    fn div(self, rhs: Self) -> Self::Output {
+        *self/(*rhs)
+    }
+}
+impl<const P:u64> Neg for &Fp<P> {
+    type Output = Fp<P>;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+impl<const P:u64> Field for Fp<P> {
+    fn one(&self)->Self {
+        Fp::one()
+    }
+    fn zero(&self)->Self {
+        Fp::zero()
+    }
+    fn is_zero(&self)->bool {
+        self.0==0
+    }
+    fn is_one(&self)->bool {
+        self.0==Self::R_MOD_P
+    }
+/// Inverse by Fermat's little theorem, x^-1 = x^(P-2).
+    fn inverse(&self)->Self {
+        if self.is_zero() {panic!("Not an invertible element!")}
+        self.pow(P-2)
+    }
+}
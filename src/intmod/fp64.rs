@@ -0,0 +1,287 @@
+//! Word-sized prime fields Z/pZ, for primes p < 2^63.
+//! [`Fp64Field`] holds the modulus and its Montgomery constants in a few machine words and is `Copy`,
+//! as are its elements [`Fp64`], so polynomials over them are plain vectors of integers with no heap
+//! allocation per coefficient. Products are computed on 128 bits and reduced with Montgomery's method,
+//! R=2^64 [Algorithm 14.32 A.Menezes P.van Oorschot S.Vanstone Handbook of applied cryptography]:
+//! the bound p < 2^63 keeps every intermediate value of the reduction below 2^128.
+//! The field with two elements, where Montgomery reduction does not apply, falls back to reduction mod 2.
+use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt, cmp::Ordering};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use rand::Rng;
+use crate::field::Field;
+use crate::integers::IntUtilities;
+use super::{Mod, PrimeField, MathError};
+
+/// Moduli must be below this bound.
+pub const FP64_MODULUS_BOUND:u64=1<<63;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp64Field {
+    modulus:u64,
+    /// -p^-1 mod 2^64, zero for p = 2
+    n_prime:u64,
+    r_mod_p:u64,
+    r2_mod_p:u64
+}
+impl Fp64Field {
+/// Checked constructor: returns the field of integers modulo p, `MathError::ModulusTooLarge` if p is
+/// not below 2^63, or `MathError::NonPrimeModulus` if p is not a prime.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{MathError,fp64::Fp64Field};
+/// use num_bigint::BigInt;
+/// let field=Fp64Field::try_new((1<<61)-1).unwrap();
+/// let x=field.new(1<<60);
+/// assert_eq!((x+x).value(),1);
+/// assert_eq!(Fp64Field::try_new(91),Err(MathError::NonPrimeModulus(BigInt::from(91))));
+/// assert_eq!(Fp64Field::try_new(u64::MAX),Err(MathError::ModulusTooLarge(BigInt::from(u64::MAX))));
+/// ```
+    pub fn try_new(p:u64)->Result<Fp64Field,MathError>{
+        if p>=FP64_MODULUS_BOUND {return Err(MathError::ModulusTooLarge(BigInt::from(p)));}
+        if BigInt::is_prime(&BigInt::from(p)) {Ok(Fp64Field::new_unchecked(p))}
+        else {Err(MathError::NonPrimeModulus(BigInt::from(p)))}
+    }
+/// Builds the field without checking that p is prime. It panics if p is even and
+/// not 2, or not below 2^63.
+    pub fn new_unchecked(p:u64)->Fp64Field{
+        if p>=FP64_MODULUS_BOUND {panic!("{} does not fit the word-sized field, the modulus must be below 2^63",p)}
+        if p.is_multiple_of(2) && p!=2 {panic!("{} is not a prime",p)}
+        let (n_prime,r_mod_p,r2_mod_p)=montgomery_constants(p);
+        Fp64Field { modulus: p, n_prime, r_mod_p, r2_mod_p }
+    }
+/// The word-sized copy of a prime field, or None if its modulus is not below 2^63.
+    pub fn from_prime_field(field:&PrimeField)->Option<Fp64Field>{
+        let p=field.0.to_u64()?;
+        if p>=FP64_MODULUS_BOUND {return None;}
+        Some(Fp64Field::new_unchecked(p))
+    }
+    pub fn modulus(&self)->u64{
+        self.modulus
+    }
+    pub fn prime_field(&self)->PrimeField{
+        PrimeField::new_unchecked(BigInt::from(self.modulus))
+    }
+/// The element n mod p.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(&self,n:u64)->Fp64{
+        Fp64 { value: self.redc((n%self.modulus) as u128*self.r2_mod_p as u128), field: *self }
+    }
+/// The element n mod p, for any integer n.
+    pub fn from_bigint(&self,n:&BigInt)->Fp64{
+        self.new(BigInt::modulo(n, &BigInt::from(self.modulus)).to_u64().unwrap())
+    }
+    pub fn zero(&self)->Fp64{
+        Fp64 { value: 0, field: *self }
+    }
+    pub fn one(&self)->Fp64{
+        Fp64 { value: self.r_mod_p, field: *self }
+    }
+    pub fn random(&self)->Fp64{
+        self.new(rand::thread_rng().gen_range(0..self.modulus))
+    }
+    fn redc(&self,t:u128)->u64{
+        montgomery_reduce(t, self.modulus, self.n_prime)
+    }
+}
+/// The constants (n',R mod p,R^2 mod p) of the Montgomery representation modulo p, with R=2^64 and
+/// n'=-p^-1 mod R. For p = 2 the representation is the identity, and the constants are (0,1,1).
+pub(crate) const fn montgomery_constants(p:u64)->(u64,u64,u64){
+    if p==2 {return (0,1,1);}
+    // Newton iteration for p^-1 mod 2^64, each step doubles the number of correct bits
+    let mut inverse=p;
+    let mut i=0;
+    while i<5 {
+        inverse=inverse.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inverse)));
+        i+=1;
+    }
+    let r_mod_p=((1u128<<64)%p as u128) as u64;
+    (inverse.wrapping_neg(),r_mod_p,(r_mod_p as u128*r_mod_p as u128%p as u128) as u64)
+}
+/// Montgomery reduction: returns tR^-1 mod p for t<pR.
+pub(crate) const fn montgomery_reduce(t:u128,p:u64,n_prime:u64)->u64{
+    if p==2 {return (t%2) as u64;}
+    let m=(t as u64).wrapping_mul(n_prime);
+    let u=((t+m as u128*p as u128)>>64) as u64;
+    if u>=p {u-p} else {u}
+}
+impl TryFrom<&PrimeField> for Fp64Field {
+    type Error = MathError;
+    fn try_from(field: &PrimeField) -> Result<Self, Self::Error> {
+        Fp64Field::from_prime_field(field).ok_or_else(||MathError::ModulusTooLarge(field.0.clone()))
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp64 {
+    value:u64,
+    field:Fp64Field
+}
+impl Fp64 {
+/// Converts an element of Z/pZ to the word-sized field with the same modulus.
+    pub fn from_mod(x:&Mod,field:&Fp64Field)->Fp64{
+        if x.modulus.0!=BigInt::from(field.modulus) {panic!("Elements of different fields!")}
+        field.from_bigint(&x.n)
+    }
+/// The standard representative in [0,p).
+    pub fn value(&self)->u64{
+        self.field.redc(self.value as u128)
+    }
+    pub fn to_mod(&self)->Mod{
+        Mod::new(BigInt::from(self.value()),self.field.prime_field())
+    }
+    pub fn field(&self)->Fp64Field{
+        self.field
+    }
+/// Computes self^exp by left-to-right square and multiply.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::fp64::Fp64Field;
+/// let field=Fp64Field::try_new(1_000_000_007).unwrap();
+/// // Fermat's little theorem
+/// assert_eq!(field.new(3).pow(1_000_000_006),field.one());
+/// ```
+    pub fn pow(&self,exp:u64)->Fp64{
+        let mut result=self.field.one();
+        for i in (0..u64::BITS-exp.leading_zeros()).rev() {
+            result=result*result;
+            if (exp>>i)&1==1 {result=result*self;}
+        }
+        result
+    }
+    fn check_field(&self,other:&Fp64){
+        if self.field!=other.field {panic!("Elements of different fields!")}
+    }
+}
+impl From<Fp64> for Mod {
+    fn from(x: Fp64) -> Self {
+        x.to_mod()
+    }
+}
+impl fmt::Display for Fp64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+impl PartialOrd for Fp64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// By value and then by modulus, the same order as the derived one of [`Mod`].
+impl Ord for Fp64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.value(),self.field.modulus).cmp(&(other.value(),other.field.modulus))
+    }
+}
+impl Add<Fp64> for Fp64 {
+    type Output = Fp64;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        // both values are below 2^63, the sum does not overflow
+        let value=self.value+rhs.value;
+        Fp64 { value: if value>=self.field.modulus {value-self.field.modulus} else {value}, field: self.field }
+    }
+}
+impl Sub<Fp64> for Fp64 {
+    type Output = Fp64;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        let value=if self.value>=rhs.value {self.value-rhs.value} else {self.value+self.field.modulus-rhs.value};
+        Fp64 { value, field: self.field }
+    }
+}
+impl Mul<Fp64> for Fp64 {
+    type Output = Fp64;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        Fp64 { value: self.field.redc(self.value as u128*rhs.value as u128), field: self.field }
+    }
+}
+impl Div<Fp64> for Fp64 {
+    type Output = Fp64;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self*rhs.inverse()
+    }
+}
+impl Neg for Fp64 {
+    type Output = Fp64;
+    fn neg(self) -> Self::Output {
+        if self.value==0 {return self;}
+        Fp64 { value: self.field.modulus-self.value, field: self.field }
+    }
+}
+impl<'a> Add<&'a Fp64> for Fp64 {
+    type Output = Fp64;
+    fn add(self, rhs: &'a Fp64) -> Self::Output {
+        self+*rhs
+    }
+}
+impl<'a> Add<&'a mut Fp64> for Fp64 {
+    type Output = Fp64;
+    fn add(self, rhs: &'a mut Fp64) -> Self::Output {
+        self+*rhs
+    }
+}
+impl<'a> Add<&'a Fp64> for &'a Fp64 {
+    type Output = Fp64;
+    fn add(self, rhs: Self) -> Self::Output {
+        *self+*rhs
+    }
+}
+impl<'a> Sub<&'a Fp64> for Fp64 {
+    type Output = Fp64;
+    fn sub(self, rhs: &'a Fp64) -> Self::Output {
+        self-*rhs
+    }
+}
+impl<'a> Sub<&'a Fp64> for &'a Fp64 {
+    type Output = Fp64;
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self-*rhs
+    }
+}
+impl<'a> Mul<&'a Fp64> for Fp64 {
+    type Output = Fp64;
+    fn mul(self, rhs: &'a Fp64) -> Self::Output {
+        self**rhs
+    }
+}
+impl<'a> Mul<&'a Fp64> for &'a Fp64 {
+    type Output = Fp64;
+    fn mul(self, rhs: Self) -> Self::Output {
+        *self**rhs
+    }
+}
+impl<'a> Div<&'a Fp64> for &'a Fp64 {
+    type Output = Fp64;
+    fn div(self, rhs: Self) -> Self::Output {
+        *self/(*rhs)
+    }
+}
+impl Neg for &Fp64 {
+    type Output = Fp64;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+impl Field for Fp64 {
+    fn one(&self)->Self {
+        self.field.one()
+    }
+    fn zero(&self)->Self {
+        self.field.zero()
+    }
+    fn is_zero(&self)->bool {
+        self.value==0
+    }
+    fn is_one(&self)->bool {
+        self.value==self.field.r_mod_p
+    }
+/// Inverse by Fermat's little theorem, x^-1 = x^(p-2).
+    fn inverse(&self)->Self {
+        if self.is_zero() {panic!("Not an invertible element!")}
+        self.pow(self.field.modulus-2)
+    }
+}
@@ -0,0 +1,307 @@
+//! The ring Z/nZ for an arbitrary positive modulus n. Unlike [`Mod`], whose modulus is a
+//! [`PrimeField`], elements of this ring need not be invertible: division is only
+//! available through the fallible [`ModN::try_inverse`].
+//! Composite arithmetic, e.g. RSA or the output of the Chinese remainder theorem, lives here.
+use std::{ops::{Add, Mul, Neg, Sub}, fmt};
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::{Zero, One};
+use crate::integers::{IntUtilities, Factorization};
+use crate::ring::{self, chinese_remainder};
+use super::{Mod, PrimeField, MathError};
+
+#[derive(Debug, Clone,Eq,PartialEq,PartialOrd, Ord, Hash)]
+pub struct IntegerModRing(pub BigInt);
+impl IntegerModRing {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(&self,num:BigInt)->ModN{
+        ModN::new(num,self.clone())
+    }
+    pub fn zero(&self)->ModN{
+        ModN::new(BigInt::zero(),self.clone())
+    }
+    pub fn one(&self)->ModN{
+        ModN::new(BigInt::one(),self.clone())
+    }
+    pub fn random(&self)->ModN{
+        let mut rng = rand::thread_rng();
+        let n= rng.gen_bigint_range(&BigInt::zero(),&self.0);
+        ModN::new(n, self.clone())
+    }
+/// Factorization of the modulus into prime powers.
+    pub fn factorization(&self)->Factorization{
+        Factorization::new(&self.0)
+    }
+/// Number of units, that is the Euler totient of the modulus.
+    pub fn unit_group_order(&self)->BigInt{
+        BigInt::euler_phi(&self.0)
+    }
+/// Returns the modulus as a [`PrimeField`] when it is prime, None otherwise.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{modn::IntegerModRing,PrimeField};
+/// use num_bigint::BigInt;
+/// assert_eq!(IntegerModRing(BigInt::from(13)).to_prime_field(),Some(PrimeField(BigInt::from(13))));
+/// assert_eq!(IntegerModRing(BigInt::from(15)).to_prime_field(),None);
+/// ```
+    pub fn to_prime_field(&self)->Option<PrimeField>{
+        PrimeField::try_new(self.0.clone()).ok()
+    }
+}
+impl From<PrimeField> for IntegerModRing {
+    fn from(field: PrimeField) -> Self {
+        IntegerModRing(field.0)
+    }
+}
+#[derive(Debug, Clone,Eq,PartialEq,PartialOrd, Ord, Hash)]
+pub struct ModN {
+    pub n:BigInt,
+    pub modulus:IntegerModRing
+}
+impl fmt::Display for ModN {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ModN({},{})", self.n,self.modulus.0)
+    }
+}
+impl From<Mod> for ModN {
+    fn from(x: Mod) -> Self {
+        ModN { n: x.n, modulus: x.modulus.into() }
+    }
+}
+impl ModN {
+    pub fn new(n:BigInt,modulus:IntegerModRing)->Self{
+        ModN { n: BigInt::modulo(&n, &modulus.0), modulus }
+    }
+    pub fn is_zero(&self)->bool{
+        self.n.is_zero()
+    }
+    pub fn is_one(&self)->bool{
+        self.n.is_one()
+    }
+/// An element is a unit if and only if it is coprime to the modulus.
+    pub fn is_unit(&self)->bool{
+        BigInt::gcd(self.n.clone(), self.modulus.0.clone()).is_one()
+    }
+/// Inverse of a unit, or `MathError::NonInvertibleElement` carrying gcd(n,modulus).
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{modn::IntegerModRing,MathError};
+/// use num_bigint::BigInt;
+/// let z15=IntegerModRing(BigInt::from(15));
+/// assert_eq!(z15.new(BigInt::from(7)).try_inverse(),Ok(z15.new(BigInt::from(13))));
+/// assert_eq!(z15.new(BigInt::from(6)).try_inverse(),Err(MathError::NonInvertibleElement(BigInt::from(3))));
+/// ```
+    pub fn try_inverse(&self)->Result<ModN,MathError>{
+        if self.modulus.0.is_one() {return Ok(self.clone());}
+        if self.n.is_zero() {return Err(MathError::NonInvertibleElement(self.modulus.0.clone()));}
+        let [u,_,d]=ring::gcdext(&self.n, &self.modulus.0);
+        if d.is_one() {Ok(ModN::new(u, self.modulus.clone()))} else {Err(MathError::NonInvertibleElement(d))}
+    }
+/// Sum of two elements, or `MathError::ModulusMismatch` if their moduli differ.
+    pub fn checked_add(&self,rhs:&ModN)->Result<ModN,MathError>{
+        if self.modulus!=rhs.modulus {return Err(MathError::ModulusMismatch);}
+        let mut sum=&self.n+&rhs.n;
+        if sum>=self.modulus.0 {sum-=&self.modulus.0;}
+        Ok(ModN { n: sum, modulus: self.modulus.clone() })
+    }
+/// Difference of two elements, or `MathError::ModulusMismatch` if their moduli differ.
+    pub fn checked_sub(&self,rhs:&ModN)->Result<ModN,MathError>{
+        if self.modulus!=rhs.modulus {return Err(MathError::ModulusMismatch);}
+        let mut difference=&self.n-&rhs.n;
+        if difference<BigInt::zero() {difference+=&self.modulus.0;}
+        Ok(ModN { n: difference, modulus: self.modulus.clone() })
+    }
+/// Product of two elements, or `MathError::ModulusMismatch` if their moduli differ.
+    pub fn checked_mul(&self,rhs:&ModN)->Result<ModN,MathError>{
+        if self.modulus!=rhs.modulus {return Err(MathError::ModulusMismatch);}
+        Ok(ModN { n: (&self.n*&rhs.n)%&self.modulus.0, modulus: self.modulus.clone() })
+    }
+/// Division by a unit.
+    pub fn try_div(&self,other:&ModN)->Result<ModN,MathError>{
+        if self.modulus!=other.modulus {return Err(MathError::ModulusMismatch);}
+        self.checked_mul(&other.try_inverse()?)
+    }
+/// Computes self^exp. Negative exponents require self to be a unit.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::modn::IntegerModRing;
+/// use num_bigint::BigInt;
+/// // textbook RSA with n = 61*53
+/// let zn=IntegerModRing(BigInt::from(3233));
+/// let c=zn.new(BigInt::from(65)).pow(&BigInt::from(17));
+/// assert_eq!(c,zn.new(BigInt::from(2790)));
+/// assert_eq!(c.pow(&BigInt::from(413)),zn.new(BigInt::from(65)));
+/// ```
+    pub fn pow(&self,exp:&BigInt)->ModN{
+        if exp<&BigInt::zero() {
+            let inverse=self.try_inverse().unwrap_or_else(|error|panic!("{}",error));
+            return inverse.pow(&-exp);
+        }
+        ModN::new(self.n.modpow(exp, &self.modulus.0), self.modulus.clone())
+    }
+/// Images of self in Z/p^eZ for each prime power p^e exactly dividing the modulus.
+/// [`ModN::chinese`] is the inverse map.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::modn::{IntegerModRing,ModN};
+/// use num_bigint::BigInt;
+/// let z360=IntegerModRing(BigInt::from(360));
+/// let x=z360.new(BigInt::from(101));
+/// let components=x.crt_components();
+/// let moduli:Vec<BigInt>=components.iter().map(|c|c.modulus.0.clone()).collect();
+/// assert_eq!(moduli,vec![BigInt::from(8),BigInt::from(9),BigInt::from(5)]);
+/// assert_eq!(ModN::chinese(components.iter().collect()),Some(x));
+/// ```
+    pub fn crt_components(&self)->Vec<ModN>{
+        self.modulus.factorization().0.iter()
+            .map(|(p,e)|ModN::new(self.n.clone(), IntegerModRing(p.pow(*e))))
+            .collect()
+    }
+/// Generalized Chinese Remainder Algorithm. Given elements x1,...,xk of Z/m1Z,...,Z/mkZ,
+/// returns the element of Z/lcm(m1,...,mk)Z congruent to each of them. The moduli need not be
+/// coprime: two congruences x=a mod m, x=b mod n are compatible if and only if gcd(m,n) divides b-a,
+/// and the output is None only when some of the congruences are inconsistent.
+/// See [10.52 H.Cohen Handbook of Elliptic and Hyperelliptic curves cryptography] for the coprime case.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::modn::{IntegerModRing,ModN};
+/// use num_bigint::BigInt;
+/// let a=IntegerModRing(BigInt::from(12)).new(BigInt::from(5));
+/// let b=IntegerModRing(BigInt::from(18)).new(BigInt::from(11));
+/// let expected=IntegerModRing(BigInt::from(36)).new(BigInt::from(29));
+/// assert_eq!(ModN::chinese(vec![&a,&b]),Some(expected));
+/// let c=IntegerModRing(BigInt::from(18)).new(BigInt::from(10));
+/// assert_eq!(ModN::chinese(vec![&a,&c]),None);
+/// ```
+    pub fn chinese(residues:Vec<&ModN>)->Option<ModN>{
+        let pairs:Vec<(BigInt,BigInt)>=residues.iter().map(|x|(x.n.clone(),x.modulus.0.clone())).collect();
+        let (x,m)=chinese_remainder(&pairs)?;
+        Some(ModN::new(x, IntegerModRing(m)))
+    }
+}
+/// Garner's algorithm [Algorithm 10.53 H.Cohen Handbook of Elliptic and Hyperelliptic curves cryptography].
+/// For a fixed list of pairwise coprime moduli m1,...,mk it precomputes the constants
+/// (m1...m(i-1))^-1 mod mi once, so that each reconstruction only costs O(k^2) small operations.
+/// The result is first obtained in mixed-radix form x = v1 + v2*m1 + v3*m1*m2 + ... .
+///
+/// # Example
+/// ```
+/// use algebra::intmod::modn::{Garner,IntegerModRing};
+/// use num_bigint::BigInt;
+/// let moduli=[BigInt::from(3),BigInt::from(5),BigInt::from(7)];
+/// let garner=Garner::new(&moduli).unwrap();
+/// let residues=[BigInt::from(2),BigInt::from(3),BigInt::from(2)];
+/// assert_eq!(garner.mixed_radix(&residues),vec![BigInt::from(2),BigInt::from(2),BigInt::from(1)]);
+/// assert_eq!(garner.reconstruct(&residues),IntegerModRing(BigInt::from(105)).new(BigInt::from(23)));
+/// assert!(Garner::new(&[BigInt::from(4),BigInt::from(6)]).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Garner {
+    moduli:Vec<BigInt>,
+    constants:Vec<BigInt>,
+    modulus:IntegerModRing
+}
+impl Garner {
+/// Precomputes the constants for the given moduli, or returns None if they are not pairwise coprime.
+    pub fn new(moduli:&[BigInt])->Option<Garner>{
+        let mut constants=vec![BigInt::one()];
+        let mut product=moduli.first()?.clone();
+        for m in moduli.iter().skip(1) {
+            let inverse=ModN::new(product.clone(), IntegerModRing(m.clone())).try_inverse().ok()?;
+            constants.push(inverse.n);
+            product*=m;
+        }
+        Some(Garner { moduli: moduli.to_vec(), constants, modulus: IntegerModRing(product) })
+    }
+/// The ring Z/(m1...mk)Z of the reconstructed elements.
+    pub fn modulus(&self)->&IntegerModRing{
+        &self.modulus
+    }
+/// Mixed-radix digits v1,...,vk of the element with the given residues, with 0<=vi<mi.
+    pub fn mixed_radix(&self,residues:&[BigInt])->Vec<BigInt>{
+        if residues.len()!=self.moduli.len() {panic!("Expected {} residues, found {}",self.moduli.len(),residues.len())}
+        let mut digits:Vec<BigInt>=Vec::with_capacity(residues.len());
+        for (i,(r,m)) in residues.iter().zip(&self.moduli).enumerate() {
+            // value of v1 + v2*m1 + ... + vi*m1...m(i-1) modulo mi, by Horner's rule
+            let mut partial=BigInt::zero();
+            for j in (0..i).rev() {
+                partial=(partial*&self.moduli[j]+&digits[j])%m;
+            }
+            digits.push(BigInt::modulo(&((r-partial)*&self.constants[i]), m));
+        }
+        digits
+    }
+/// The element of Z/(m1...mk)Z with the given residues.
+    pub fn reconstruct(&self,residues:&[BigInt])->ModN{
+        let digits=self.mixed_radix(residues);
+        let mut x=BigInt::zero();
+        for (v,m) in digits.iter().zip(&self.moduli).rev() {
+            x=x*m+v;
+        }
+        ModN { n: x, modulus: self.modulus.clone() }
+    }
+}
+impl<'a> Add<&'a ModN> for &'a ModN {
+    type Output = ModN;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
+}
+impl<'a> Sub<&'a ModN> for &'a ModN {
+    type Output = ModN;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
+}
+impl<'a> Mul<&'a ModN> for &'a ModN {
+    type Output = ModN;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
+}
+impl Neg for &ModN {
+    type Output = ModN;
+    fn neg(self) -> Self::Output {
+        ModN::new(-&self.n, self.modulus.clone())
+    }
+}
+impl Add<ModN> for ModN {
+    type Output = ModN;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self+&rhs
+    }
+}
+impl<'a> Add<&'a ModN> for ModN {
+    type Output = ModN;
+    fn add(self, rhs: &'a ModN) -> Self::Output {
+        &self+rhs
+    }
+}
+impl Sub<ModN> for ModN {
+    type Output = ModN;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self-&rhs
+    }
+}
+impl Mul<ModN> for ModN {
+    type Output = ModN;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self*&rhs
+    }
+}
+impl<'a> Mul<&'a ModN> for ModN {
+    type Output = ModN;
+    fn mul(self, rhs: &'a ModN) -> Self::Output {
+        &self*rhs
+    }
+}
+impl Neg for ModN {
+    type Output = ModN;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
@@ -0,0 +1,254 @@
+//! Montgomery representation of the prime field Z/pZ.
+//! An element x is stored as xR mod p, with R=2^(64k) the smallest power of 2^64 above p, so that
+//! multiplication only needs products, masks and shifts instead of a division by p
+//! [Algorithm 14.32 A.Menezes P.van Oorschot S.Vanstone Handbook of applied cryptography].
+//! The constants R mod p, R^2 mod p and n'=-p^-1 mod R are computed once per [`MontgomeryField`]
+//! and shared by all its elements. [`MontgomeryMod`] implements [`Field`], so generic code such as
+//! `Poly<MontgomeryMod>` works unchanged, and converts to and from [`Mod`].
+use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt, cmp::Ordering, sync::Arc};
+use num_bigint::BigInt;
+use num_traits::{Zero, One};
+use crate::field::Field;
+use crate::integers::IntUtilities;
+use super::{Mod, PrimeField};
+use super::modn::IntegerModRing;
+
+#[derive(Debug, PartialEq, Eq)]
+struct MontgomeryParams {
+    modulus:BigInt,
+    /// R=2^shift
+    shift:u64,
+    mask:BigInt,
+    r_mod_p:BigInt,
+    r2_mod_p:BigInt,
+    /// -p^-1 mod R
+    n_prime:BigInt
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryField(Arc<MontgomeryParams>);
+impl MontgomeryField {
+/// Precomputes the Montgomery constants of an odd prime field.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{PrimeField,montgomery::MontgomeryField};
+/// use num_bigint::BigInt;
+/// let field=PrimeField::try_new(BigInt::from(1_000_000_007)).unwrap();
+/// let montgomery=MontgomeryField::from_prime_field(&field);
+/// let x=montgomery.new(BigInt::from(123_456_789));
+/// let y=montgomery.new(BigInt::from(987_654_321));
+/// assert_eq!((&x*&y).to_mod(),field.new(BigInt::from(123_456_789))*field.new(BigInt::from(987_654_321)));
+/// ```
+    pub fn from_prime_field(field:&PrimeField)->MontgomeryField{
+        let modulus=field.0.clone();
+        if BigInt::is_even(&modulus) {panic!("Montgomery arithmetic needs an odd modulus, found {}",modulus)}
+        let shift=modulus.bits().div_ceil(64)*64;
+        let r=BigInt::one()<<shift;
+        let mask=&r-BigInt::one();
+        let inverse=IntegerModRing(r.clone()).new(modulus.clone()).try_inverse().unwrap().n;
+        let params=MontgomeryParams {
+            r_mod_p:&r%&modulus,
+            r2_mod_p:(&r*&r)%&modulus,
+            n_prime:&r-inverse,
+            modulus,
+            shift,
+            mask
+        };
+        MontgomeryField(Arc::new(params))
+    }
+    pub fn modulus(&self)->&BigInt{
+        &self.0.modulus
+    }
+    pub fn prime_field(&self)->PrimeField{
+        PrimeField::new_unchecked(self.0.modulus.clone())
+    }
+/// The element n mod p in Montgomery form.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(&self,n:BigInt)->MontgomeryMod{
+        let n=BigInt::modulo(&n, &self.0.modulus);
+        MontgomeryMod { value: self.redc(n*&self.0.r2_mod_p), field: self.clone() }
+    }
+    pub fn zero(&self)->MontgomeryMod{
+        MontgomeryMod { value: BigInt::zero(), field: self.clone() }
+    }
+    pub fn one(&self)->MontgomeryMod{
+        MontgomeryMod { value: self.0.r_mod_p.clone(), field: self.clone() }
+    }
+/// Montgomery reduction: returns tR^-1 mod p for 0<=t<pR.
+    fn redc(&self,t:BigInt)->BigInt{
+        let params=&self.0;
+        let m=((&t & &params.mask)*&params.n_prime) & &params.mask;
+        let mut u=(t+m*&params.modulus)>>params.shift;
+        if u>=params.modulus {u-=&params.modulus;}
+        u
+    }
+}
+impl From<&PrimeField> for MontgomeryField {
+    fn from(field: &PrimeField) -> Self {
+        MontgomeryField::from_prime_field(field)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryMod {
+    value:BigInt,
+    field:MontgomeryField
+}
+impl MontgomeryMod {
+/// Converts an element of Z/pZ to Montgomery form in the given field.
+    pub fn from_mod(x:&Mod,field:&MontgomeryField)->MontgomeryMod{
+        if x.modulus.0!=field.0.modulus {panic!("Elements of different fields!")}
+        field.new(x.n.clone())
+    }
+/// The standard representative in [0,p).
+    pub fn to_bigint(&self)->BigInt{
+        self.field.redc(self.value.clone())
+    }
+    pub fn to_mod(&self)->Mod{
+        Mod::new(self.to_bigint(),self.field.prime_field())
+    }
+    pub fn field(&self)->&MontgomeryField{
+        &self.field
+    }
+/// Computes self^exp by left-to-right square and multiply, negative exponents are allowed
+/// for non-zero elements.
+    pub fn pow(&self,exp:&BigInt)->MontgomeryMod{
+        let base=if exp<&BigInt::zero() {self.inverse()} else {self.clone()};
+        let exp=if exp<&BigInt::zero() {-exp} else {exp.clone()};
+        let mut result=self.field.one();
+        for i in (0..exp.bits()).rev() {
+            result=&result*&result;
+            if exp.bit(i) {result=&result*&base;}
+        }
+        result
+    }
+    fn check_field(&self,other:&MontgomeryMod){
+        if self.field!=other.field {panic!("Elements of different fields!")}
+    }
+}
+impl From<MontgomeryMod> for Mod {
+    fn from(x: MontgomeryMod) -> Self {
+        x.to_mod()
+    }
+}
+impl fmt::Display for MontgomeryMod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+impl PartialOrd for MontgomeryMod {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// Compares the standard representatives first and then the moduli, so that the order agrees with
+/// the derived order of the corresponding [`Mod`] values.
+impl Ord for MontgomeryMod {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.to_bigint(),self.field.modulus()).cmp(&(other.to_bigint(),other.field.modulus()))
+    }
+}
+impl<'a> Add<&'a MontgomeryMod> for &'a MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_field(rhs);
+        let mut value=&self.value+&rhs.value;
+        if &value>=self.field.modulus() {value-=self.field.modulus();}
+        MontgomeryMod { value, field: self.field.clone() }
+    }
+}
+impl<'a> Sub<&'a MontgomeryMod> for &'a MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_field(rhs);
+        let mut value=&self.value-&rhs.value;
+        if value<BigInt::zero() {value+=self.field.modulus();}
+        MontgomeryMod { value, field: self.field.clone() }
+    }
+}
+impl<'a> Mul<&'a MontgomeryMod> for &'a MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_field(rhs);
+        MontgomeryMod { value: self.field.redc(&self.value*&rhs.value), field: self.field.clone() }
+    }
+}
+impl<'a> Div<&'a MontgomeryMod> for &'a MontgomeryMod {
+    type Output = MontgomeryMod;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self*&rhs.inverse()
+    }
+}
+impl Neg for &MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn neg(self) -> Self::Output {
+        if self.value.is_zero() {return self.clone();}
+        MontgomeryMod { value: self.field.modulus()-&self.value, field: self.field.clone() }
+    }
+}
+impl Add<MontgomeryMod> for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self+&rhs
+    }
+}
+impl<'a> Add<&'a MontgomeryMod> for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn add(self, rhs: &'a MontgomeryMod) -> Self::Output {
+        &self+rhs
+    }
+}
+impl<'a> Add<&'a mut MontgomeryMod> for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn add(self, rhs: &'a mut MontgomeryMod) -> Self::Output {
+        &self+&*rhs
+    }
+}
+impl Sub<MontgomeryMod> for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self-&rhs
+    }
+}
+impl Mul<MontgomeryMod> for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self*&rhs
+    }
+}
+impl<'a> Mul<&'a MontgomeryMod> for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn mul(self, rhs: &'a MontgomeryMod) -> Self::Output {
+        &self*rhs
+    }
+}
+impl Div<MontgomeryMod> for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn div(self, rhs: Self) -> Self::Output {
+        &self/&rhs
+    }
+}
+impl Neg for MontgomeryMod {
+    type Output = MontgomeryMod;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+impl Field for MontgomeryMod {
+    fn one(&self)->Self {
+        self.field.one()
+    }
+    fn zero(&self)->Self {
+        self.field.zero()
+    }
+    fn is_zero(&self)->bool {
+        self.value.is_zero()
+    }
+    fn is_one(&self)->bool {
+        self.value==self.field.0.r_mod_p
+    }
+    fn inverse(&self)->Self {
+        let inverse=self.to_mod().inverse();
+        self.field.new(inverse.n)
+    }
+}
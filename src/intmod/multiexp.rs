@@ -0,0 +1,120 @@
+//! Simultaneous multi-exponentiation in prime fields: products g1^e1*...*gk^ek share their squarings,
+//! so they cost about as many squarings as a single exponentiation instead of k times as many.
+//! [`Mod::multi_pow_straus`] is the Straus–Shamir trick, suited to a few bases, and
+//! [`Mod::multi_pow_pippenger`] is Pippenger's bucket method, suited to many bases.
+//! [`Mod::multi_pow`] chooses between them. Negative exponents are handled by inverting the bases
+//! all at once with [`batch_inverse`].
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+use crate::field::{Field, batch_inverse};
+use super::Mod;
+
+/// At most this many bases are combined in one table by the Straus–Shamir trick, whose table has
+/// 2^k entries.
+const STRAUS_MAX_BASES:usize=4;
+impl Mod {
+/// Computes g1^e1*...*gk^ek. A single power is computed by [`Mod::pow_mod`], up to
+/// a few bases by [`Mod::multi_pow_straus`], otherwise by [`Mod::multi_pow_pippenger`].
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{Mod,PrimeField};
+/// use num_bigint::BigInt;
+/// let z101=PrimeField::try_new(BigInt::from(101)).unwrap();
+/// let bases=[z101.new(BigInt::from(2)),z101.new(BigInt::from(3))];
+/// let exps=[BigInt::from(10),BigInt::from(-1)];
+/// // 2^10 = 14 mod 101 and 3^-1 = 34 mod 101
+/// assert_eq!(Mod::multi_pow(&bases,&exps),z101.new(BigInt::from(14*34)));
+/// ```
+    pub fn multi_pow(bases:&[Mod],exps:&[BigInt])->Mod{
+        check_lengths(bases, exps);
+        match bases.len() {
+            1=>bases[0].clone().pow_mod(&exps[0]),
+            k if k<=STRAUS_MAX_BASES=>Mod::multi_pow_straus(bases, exps),
+            _=>Mod::multi_pow_pippenger(bases, exps)
+        }
+    }
+/// Straus–Shamir trick [Algorithm 14.88 A.Menezes P.van Oorschot S.Vanstone Handbook of applied cryptography]:
+/// the products of every subset of a group of bases are precomputed, then the exponents are scanned
+/// one bit at a time, with one squaring and at most one product per bit.
+/// Bases are taken in groups of at most four, to keep the tables small.
+    pub fn multi_pow_straus(bases:&[Mod],exps:&[BigInt])->Mod{
+        check_lengths(bases, exps);
+        let (bases,exps)=non_negative(bases, exps);
+        let mut result=bases[0].one();
+        for (bases,exps) in bases.chunks(STRAUS_MAX_BASES).zip(exps.chunks(STRAUS_MAX_BASES)) {
+            // table[s] is the product of the bases indexed by the bits of s
+            let mut table=vec![bases[0].one()];
+            for s in 1usize..1<<bases.len() {
+                let lowest=s.trailing_zeros() as usize;
+                table.push(&table[s&(s-1)]*&bases[lowest]);
+            }
+            let bits=exps.iter().map(|e|e.bits()).max().unwrap_or(0);
+            let mut partial=bases[0].one();
+            for t in (0..bits).rev() {
+                partial=&partial*&partial;
+                let s=exps.iter().enumerate().fold(0usize,|s,(i,e)|if e.bit(t) {s|1<<i} else {s});
+                if s!=0 {partial=&partial*&table[s];}
+            }
+            result=&result*&partial;
+        }
+        result
+    }
+/// Pippenger's bucket method [N.Pippenger On the evaluation of powers and related problems, 1976]:
+/// the exponents are cut in windows of c bits, with c about log2(k). For each window the bases are
+/// sorted into 2^c-1 buckets by their digit, and the product of bucket(d)^d is recovered with
+/// running products, at the cost of two multiplications per bucket.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::{Mod,PrimeField};
+/// use num_bigint::BigInt;
+/// let z1009=PrimeField::try_new(BigInt::from(1009)).unwrap();
+/// let bases:Vec<Mod>=(2..40).map(|n|z1009.new(BigInt::from(n))).collect();
+/// let exps:Vec<BigInt>=(0..38).map(|n|BigInt::from(1000+7*n)).collect();
+/// assert_eq!(Mod::multi_pow_pippenger(&bases,&exps),Mod::multi_pow_straus(&bases,&exps));
+/// ```
+    pub fn multi_pow_pippenger(bases:&[Mod],exps:&[BigInt])->Mod{
+        check_lengths(bases, exps);
+        let (bases,exps)=non_negative(bases, exps);
+        let c=(usize::BITS-1-bases.len().leading_zeros()).max(1) as u64;
+        let bits=exps.iter().map(|e|e.bits()).max().unwrap_or(0);
+        let mut result=bases[0].one();
+        for window in (0..bits.div_ceil(c)).rev() {
+            for _ in 0..c {result=&result*&result;}
+            let mut buckets:Vec<Option<Mod>>=vec![None;(1<<c)-1];
+            for (g,e) in bases.iter().zip(&exps) {
+                let digit=(0..c).fold(0usize,|d,j|if e.bit(window*c+j) {d|1<<j} else {d});
+                if digit==0 {continue;}
+                buckets[digit-1]=Some(match buckets[digit-1].take() {Some(b)=>b*g,None=>g.clone()});
+            }
+            // running = bucket(2^c-1)*...*bucket(d), and the product of the running values is
+            // the product of bucket(d)^d
+            let mut running:Option<Mod>=None;
+            let mut window_product:Option<Mod>=None;
+            for bucket in buckets.into_iter().rev() {
+                running=match (running,bucket) {
+                    (Some(r),Some(b))=>Some(r*b),
+                    (r,b)=>r.or(b)
+                };
+                if let Some(r)=&running {
+                    window_product=Some(match window_product {Some(w)=>w*r,None=>r.clone()});
+                }
+            }
+            if let Some(w)=window_product {result=result*w;}
+        }
+        result
+    }
+}
+fn check_lengths(bases:&[Mod],exps:&[BigInt]){
+    if bases.is_empty() {panic!("Expected at least one base")}
+    if bases.len()!=exps.len() {panic!("Expected {} exponents, found {}",bases.len(),exps.len())}
+}
+/// Replaces g^e by (g^-1)^(-e) for negative e, inverting all such bases at once.
+fn non_negative(bases:&[Mod],exps:&[BigInt])->(Vec<Mod>,Vec<BigInt>){
+    let negative:Vec<usize>=(0..exps.len()).filter(|&i|exps[i]<BigInt::zero()).collect();
+    let inverses=batch_inverse(&negative.iter().map(|&i|bases[i].clone()).collect::<Vec<Mod>>());
+    let mut bases=bases.to_vec();
+    for (i,inverse) in negative.into_iter().zip(inverses) {bases[i]=inverse;}
+    (bases,exps.iter().map(|e|e.abs()).collect())
+}
@@ -0,0 +1,145 @@
+//! n-th roots in prime fields.
+//! Roots of prime degree r are computed with the Adleman–Manders–Miller algorithm, which reduces to
+//! Tonelli–Shanks for r = 2, roots of composite degree are taken one prime at a time.
+//! See [L.Adleman K.Manders G.Miller On taking roots in finite fields, 1977] and
+//! [Z.Cao Q.Sha X.Fan Adleman-Manders-Miller root extraction method revisited, 2011].
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use crate::integers::{IntUtilities, Factorization};
+use crate::field::Field;
+use super::Mod;
+use super::modn::IntegerModRing;
+use super::dlog::baby_step_giant_step;
+
+impl Mod {
+/// Checks whether self is an n-th power, that is self^((p-1)/gcd(n,p-1))=1. Zero is an n-th power.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z31=PrimeField::try_new(BigInt::from(31)).unwrap();
+/// assert!(z31.new(BigInt::from(5)).is_nth_power(&BigInt::from(5)));
+/// assert!(!z31.new(BigInt::from(3)).is_nth_power(&BigInt::from(5)));
+/// assert!(z31.new(BigInt::from(3)).is_nth_power(&BigInt::from(7)));
+/// ```
+    pub fn is_nth_power(&self,n:&BigInt)->bool{
+        check_degree(n);
+        if self.is_zero() {return true;}
+        let m=self.modulus.group_order();
+        let d=BigInt::gcd(n.clone(), m.clone());
+        self.clone().pow_mod(&(m/d)).is_one()
+    }
+/// An n-th root of self, or None if self is not an n-th power.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z31=PrimeField::try_new(BigInt::from(31)).unwrap();
+/// let x=z31.new(BigInt::from(5));
+/// let mut root=x.nth_root(&BigInt::from(5)).unwrap();
+/// assert_eq!(root.pow_mod(&BigInt::from(5)),x);
+/// assert_eq!(z31.new(BigInt::from(3)).nth_root(&BigInt::from(5)),None);
+/// ```
+    pub fn nth_root(&self,n:&BigInt)->Option<Mod>{
+        if !self.is_nth_power(n) {return None;}
+        if self.is_zero() {return Some(self.clone());}
+        let m=self.modulus.group_order();
+        let mut remaining=n.clone();
+        let mut x=self.clone();
+        for (r,e) in Factorization::new(n).0 {
+            for _ in 0..e {
+                remaining/=&r;
+                let mut y=prime_root(&x, &r);
+                if (&m%&r).is_zero() && !y.is_nth_power(&remaining) {
+                    // move to the r-th root of x which is still a remaining-th power:
+                    // y*zeta^i for the i with (y*zeta^i)^(m/g)=1
+                    let cofactor=&m/BigInt::gcd(remaining.clone(), m.clone());
+                    let zeta=root_of_unity(&self.modulus.one(), &r, 1, &m);
+                    let w=zeta.clone().pow_mod(&cofactor);
+                    let target=y.clone().pow_mod(&cofactor).inverse();
+                    let i=baby_step_giant_step(&w, &target, &r)?;
+                    y=&y*&zeta.clone().pow_mod(&i);
+                }
+                x=y;
+            }
+        }
+        Some(x)
+    }
+/// All the n-th roots of self, sorted. There are gcd(n,p-1) of them when self is a non-zero n-th power.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z31=PrimeField::try_new(BigInt::from(31)).unwrap();
+/// let roots=z31.one().all_nth_roots(&BigInt::from(3));
+/// assert_eq!(roots,vec![z31.one(),z31.new(BigInt::from(5)),z31.new(BigInt::from(25))]);
+/// ```
+    pub fn all_nth_roots(&self,n:&BigInt)->Vec<Mod>{
+        let root=match self.nth_root(n) {
+            Some(root)=>root,
+            None=>return Vec::new()
+        };
+        if self.is_zero() {return vec![root];}
+        let m=self.modulus.group_order();
+        let d=BigInt::gcd(n.clone(), m.clone());
+        // omega generates the d-th roots of unity
+        let mut omega=self.modulus.one();
+        for (r,e) in Factorization::new(&d).0 {
+            omega=&omega*&root_of_unity(&self.modulus.one(), &r, e, &m);
+        }
+        let mut roots=Vec::new();
+        let mut x=root.clone();
+        loop {
+            roots.push(x.clone());
+            x=&x*&omega;
+            if x==root {break;}
+        }
+        roots.sort();
+        roots
+    }
+}
+fn check_degree(n:&BigInt){
+    if n<=&BigInt::zero() {panic!("The degree of a root must be positive, found {}",n)}
+}
+/// An r-th root of delta, an r-th power, for r prime [Adleman–Manders–Miller, Algorithm 1 of Cao, Sha, Fan].
+fn prime_root(delta:&Mod,r:&BigInt)->Mod{
+    let m=delta.modulus.group_order();
+    if !(&m%r).is_zero() {
+        // x -> x^r is a bijection
+        let exponent=IntegerModRing(m.clone()).new(r.clone()).try_inverse().unwrap();
+        return delta.clone().pow_mod(&exponent.n);
+    }
+    let mut t=0u32;
+    let mut s=m.clone();
+    while (&s%r).is_zero() {s/=r; t+=1;}
+    // r*alpha = 1 mod s
+    let alpha=if s.is_one() {BigInt::one()} else {IntegerModRing(s.clone()).new(r.clone()).try_inverse().unwrap().n};
+    let mut rho=non_residue(&delta.modulus.one(), r, &m);
+    let a=rho.clone().pow_mod(&(r.pow(t-1)*&s));
+    let mut b=delta.clone().pow_mod(&(r*&alpha-BigInt::one()));
+    let mut c=rho.pow_mod(&s);
+    let mut h=delta.modulus.one();
+    for i in 1..t {
+        let d=b.clone().pow_mod(&r.pow(t-1-i));
+        let j=if d.is_one() {BigInt::zero()} else {baby_step_giant_step(&a, &d.inverse(), r).unwrap()};
+        let c_r=c.clone().pow_mod(r);
+        b=&b*&c_r.clone().pow_mod(&j);
+        h=&h*&c.pow_mod(&j);
+        c=c_r;
+    }
+    &delta.clone().pow_mod(&alpha)*&h
+}
+/// The least positive integer which is not an r-th power, for r dividing m=p-1.
+fn non_residue(one:&Mod,r:&BigInt,m:&BigInt)->Mod{
+    let exponent=m/r;
+    let mut z=one+one;
+    while z.clone().pow_mod(&exponent).is_one() {z=&z+one;}
+    z
+}
+/// A primitive r^e-th root of unity, where r^e divides m=p-1.
+fn root_of_unity(one:&Mod,r:&BigInt,e:u32,m:&BigInt)->Mod{
+    non_residue(one, r, m).pow_mod(&(m/r.pow(e)))
+}
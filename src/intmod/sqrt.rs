@@ -0,0 +1,251 @@
+//! Square roots modulo primes, prime powers and composite moduli.
+//! Modulo an odd prime, [`Mod::check_sqrt_mod_prime`] chooses among the algorithms of this module
+//! according to p mod 8. Roots are lifted to p^k with Hensel's lemma, the case p = 2 being handled
+//! separately, and roots modulo a composite n are then combined with the Chinese remainder theorem.
+//! See [H.Cohen A course in computational number theory, section 1.5] and
+//! [R.Crandall C.Pomerance Prime numbers, a computational perspective, section 2.3].
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use crate::integers::{IntUtilities, Factorization, QuadraticCharacter};
+use crate::field::Field;
+use super::{Mod, PrimeField, ModResult, MathError};
+use super::modn::{IntegerModRing, ModN};
+
+impl PrimeField {
+/// The least quadratic non-residue modulo p. The search is deterministic and, under GRH,
+/// stops before 2*log(p)^2. It panics for p = 2, which has no non-residues.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use num_bigint::BigInt;
+/// let z41=PrimeField::try_new(BigInt::from(41)).unwrap();
+/// assert_eq!(z41.quadratic_non_residue(),z41.new(BigInt::from(3)));
+/// ```
+    pub fn quadratic_non_residue(&self)->Mod{
+        if self.0==BigInt::from(2) {panic!("Every element is a square modulo 2!")}
+        let mut z=BigInt::from(2);
+        while BigInt::kronecker(&z, &self.0)!=-1 {z+=1;}
+        self.new(z)
+    }
+}
+impl Mod {
+/// The quadratic character of the element, i.e. its Legendre symbol (n/p), computed with
+/// [`IntUtilities::kronecker`] instead of Euler's criterion n^((p-1)/2). Every non-zero element is
+/// a residue modulo 2.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use algebra::integers::QuadraticCharacter;
+/// use num_bigint::BigInt;
+/// let z11=PrimeField::try_new(BigInt::from(11)).unwrap();
+/// assert_eq!(z11.new(BigInt::from(5)).quadratic_character(),QuadraticCharacter::Residue);
+/// assert_eq!(z11.new(BigInt::from(2)).quadratic_character(),QuadraticCharacter::NonResidue);
+/// assert_eq!(z11.new(BigInt::from(0)).quadratic_character(),QuadraticCharacter::Zero);
+/// ```
+    pub fn quadratic_character(&self)->QuadraticCharacter{
+        if self.is_zero() {return QuadraticCharacter::Zero;}
+        if self.modulus.0==BigInt::from(2) {return QuadraticCharacter::Residue;}
+        BigInt::legendre(&self.n, &self.modulus.0)
+    }
+/// Whether the element is a non-zero square. Zero is a square but not a quadratic residue.
+    pub fn is_quadratic_residue(&self)->bool{
+        self.quadratic_character().is_residue()
+    }
+/// Square root for p = 3 mod 4: x = a^((p+1)/4).
+    pub fn sqrt_3_mod_4(&self)->ModResult{
+        let p=&self.modulus.0;
+        if p%4u8!=BigInt::from(3) {panic!("{} is not 3 mod 4",p)}
+        let x=self.n.modpow(&((p+BigInt::one())>>2), p);
+        checked_root(self, self.modulus.new(x))
+    }
+/// Atkin's square root for p = 5 mod 8 [Algorithm 3.37 A.Menezes P.van Oorschot S.Vanstone Handbook of applied cryptography]:
+/// with b=(2a)^((p-5)/8) and i=2ab^2, x = ab(i-1).
+    pub fn sqrt_atkin(&self)->ModResult{
+        let p=&self.modulus.0;
+        if p%8u8!=BigInt::from(5) {panic!("{} is not 5 mod 8",p)}
+        let two_a=self+self;
+        let b=self.modulus.new(two_a.n.modpow(&((p-BigInt::from(5))>>3), p));
+        let i=&(&two_a*&b)*&b;
+        let x=&(self*&b)*&(&i-&self.one());
+        checked_root(self, x)
+    }
+/// Tonelli–Shanks algorithm [Algorithm 1.5.1 H.Cohen A course in computational number theory],
+/// with the deterministic non-residue of [`PrimeField::quadratic_non_residue`].
+/// It costs O(log(p)+e^2) multiplications, where 2^e is the largest power of 2 dividing p-1.
+    pub fn sqrt_tonelli_shanks(&self)->ModResult{
+        if self.is_zero(){return Ok(self.zero());}
+        let p=&self.modulus.0;
+        let (even_part,e)=BigInt::even_part(&(p-BigInt::one()));
+        let q=(p-BigInt::one())/even_part;
+        // y generates the 2-Sylow subgroup, of order 2^e
+        let mut y=self.modulus.quadratic_non_residue().n.modpow(&q, p);
+        let mut r=e;
+        let mut x=self.n.modpow(&((&q-BigInt::one())>>1), p);
+        let mut b=&self.n*&x%p*&x%p;
+        x=&x*&self.n%p;
+        while !b.is_one(){
+            // least m with b^(2^m)=1
+            let mut m=0u64;
+            let mut power=b.clone();
+            while !power.is_one() {
+                power=&power*&power%p;
+                m+=1;
+            }
+            if m==r {return Err(MathError::QuadraticNonResidueModP);}
+            let t=y.modpow(&(BigInt::one()<<(r-m-1)), p);
+            y=&t*&t%p;
+            r=m;
+            x=&x*&t%p;
+            b=&b*&y%p;
+        }
+        Ok(smaller_root(self.modulus.new(x)))
+    }
+/// Cipolla's algorithm [R.Crandall C.Pomerance Prime numbers, a computational perspective, Algorithm 2.3.9]:
+/// for t with t^2-a a non-residue, x = (t+w)^((p+1)/2) in F_p(w), w^2=t^2-a.
+/// Its cost does not depend on the 2-adic valuation of p-1.
+    pub fn sqrt_cipolla(&self)->ModResult{
+        if self.is_zero(){return Ok(self.zero());}
+        let p=&self.modulus.0;
+        if self.quadratic_character()==QuadraticCharacter::NonResidue {return Err(MathError::QuadraticNonResidueModP);}
+        let mut t=BigInt::one();
+        let w2=loop {
+            let candidate=BigInt::modulo(&(&t*&t-&self.n), p);
+            if BigInt::legendre(&candidate, p)==QuadraticCharacter::NonResidue {break candidate;}
+            t+=1;
+        };
+        // (x0+x1*w)*(y0+y1*w) = (x0*y0+x1*y1*w2) + (x0*y1+x1*y0)*w
+        let mul=|x:&(BigInt,BigInt),y:&(BigInt,BigInt)|((&x.0*&y.0+&x.1*&y.1%p*&w2)%p,(&x.0*&y.1+&x.1*&y.0)%p);
+        let exp:BigInt=(p+BigInt::one())>>1;
+        let mut result=(BigInt::one(),BigInt::zero());
+        let base=(t,BigInt::one());
+        for i in (0..exp.bits()).rev() {
+            result=mul(&result,&result);
+            if exp.bit(i) {result=mul(&result,&base);}
+        }
+        checked_root(self, self.modulus.new(result.0))
+    }
+}
+/// Returns the smaller of x and -x, or an error if x^2 is not a.
+fn checked_root(a:&Mod,x:Mod)->ModResult{
+    if &(&x*&x)==a {Ok(smaller_root(x))} else {Err(MathError::QuadraticNonResidueModP)}
+}
+fn smaller_root(x:Mod)->Mod{
+    let neg_x=-x.clone();
+    if x<neg_x {x} else {neg_x}
+}
+
+/// All the square roots of a modulo p^k, with p prime and k>0, sorted in increasing order.
+/// The output is empty when a is not a square modulo p^k.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::sqrt::sqrt_mod_prime_power;
+/// use num_bigint::BigInt;
+/// let roots=sqrt_mod_prime_power(&BigInt::from(2),&BigInt::from(7),3);
+/// assert_eq!(roots,vec![BigInt::from(108),BigInt::from(235)]);
+/// let roots=sqrt_mod_prime_power(&BigInt::from(17),&BigInt::from(2),5);
+/// assert_eq!(roots,vec![BigInt::from(7),BigInt::from(9),BigInt::from(23),BigInt::from(25)]);
+/// assert!(sqrt_mod_prime_power(&BigInt::from(3),&BigInt::from(5),2).is_empty());
+/// ```
+pub fn sqrt_mod_prime_power(a:&BigInt,p:&BigInt,k:u32)->Vec<BigInt>{
+    let modulus=p.pow(k);
+    let a=BigInt::modulo(a, &modulus);
+    if a.is_zero() {
+        // x^2 = 0 mod p^k if and only if p^ceil(k/2) divides x
+        let step=p.pow(k.div_ceil(2));
+        return multiples(&step, &p.pow(k/2)).collect();
+    }
+    let mut v=0u32;
+    let mut unit=a;
+    while (&unit%p).is_zero() {unit/=p; v+=1;}
+    if v%2==1 {return Vec::new();}
+    // x = p^(v/2) y with y^2 = unit mod p^(k-v), y being defined modulo p^(k-v/2)
+    let unit_roots=sqrt_of_unit(&unit, p, k-v);
+    let shift=p.pow(v/2);
+    let lift_step=p.pow(k-v);
+    let mut roots:Vec<BigInt>=unit_roots.iter()
+        .flat_map(|y|multiples(&lift_step, &shift).map(|t|(&shift*(y+t))%&modulus).collect::<Vec<_>>())
+        .collect();
+    roots.sort();
+    roots
+}
+/// The first count multiples of step: 0, step, 2*step, ...
+fn multiples(step:&BigInt,count:&BigInt)->impl Iterator<Item=BigInt>{
+    let step=step.clone();
+    let end=count*&step;
+    std::iter::successors(Some(BigInt::zero()),move |t|Some(t+&step)).take_while(move |t|*t<end)
+}
+/// Square roots of a unit modulo p^k.
+fn sqrt_of_unit(a:&BigInt,p:&BigInt,k:u32)->Vec<BigInt>{
+    let modulus=p.pow(k);
+    let two=BigInt::from(2);
+    if p==&two {
+        let a=BigInt::modulo(a, &modulus);
+        return match k {
+            1=>vec![BigInt::one()],
+            2=>if (&a%4u32).is_one() {vec![BigInt::one(),BigInt::from(3)]} else {Vec::new()},
+            _=>{
+                if !(&a%8u32).is_one() {return Vec::new();}
+                // r^2 = a mod 2^i, corrected one bit at a time
+                let mut r=BigInt::one();
+                for i in 3..k {
+                    if !BigInt::modulo(&(&r*&r-&a), &(BigInt::one()<<(i+1))).is_zero() {
+                        r+=BigInt::one()<<(i-1);
+                    }
+                }
+                let half=BigInt::one()<<(k-1);
+                vec![r.clone(),&modulus-&r,(&r+&half)%&modulus,BigInt::modulo(&(&half-&r), &modulus)]
+            }
+        };
+    }
+    let mut root=match Mod::new(a.clone(), PrimeField::new_unchecked(p.clone())).check_sqrt_mod_prime() {
+        Ok(root)=>root.n,
+        Err(_)=>return Vec::new()
+    };
+    // Newton iteration r -> r-(r^2-a)/(2r), doubling the precision at each step
+    let mut precision=1;
+    while precision<k {
+        precision=(2*precision).min(k);
+        let m=p.pow(precision);
+        let ring=IntegerModRing(m.clone());
+        let inverse=ring.new(&two*&root).try_inverse().unwrap();
+        root=BigInt::modulo(&(&root-(&root*&root-a)*inverse.n), &m);
+    }
+    vec![root.clone(),&modulus-&root]
+}
+impl ModN {
+/// All the square roots of self, sorted. The modulus is factored first: when its factorization
+/// is already known, use [`ModN::sqrts_with`].
+///
+/// # Example
+/// ```
+/// use algebra::intmod::modn::IntegerModRing;
+/// use num_bigint::BigInt;
+/// // Rabin decryption: the plaintext 20 is one of the four square roots of its square modulo 7*11
+/// let z77=IntegerModRing(BigInt::from(77));
+/// let c=z77.new(BigInt::from(20*20));
+/// let roots:Vec<BigInt>=c.sqrts().into_iter().map(|x|x.n).collect();
+/// assert_eq!(roots,vec![BigInt::from(13),BigInt::from(20),BigInt::from(57),BigInt::from(64)]);
+/// ```
+    pub fn sqrts(&self)->Vec<ModN>{
+        self.sqrts_with(&self.modulus.factorization())
+    }
+/// All the square roots of self, given the factorization of the modulus. The roots modulo each
+/// prime power are combined with [`ModN::chinese`].
+    pub fn sqrts_with(&self,factorization:&Factorization)->Vec<ModN>{
+        if factorization.value()!=self.modulus.0 {panic!("{:?} is not a factorization of {}",factorization.0,self.modulus.0)}
+        let mut roots=vec![IntegerModRing(BigInt::one()).zero()];
+        for (p,e) in &factorization.0 {
+            let ring=IntegerModRing(p.pow(*e));
+            let local_roots=sqrt_mod_prime_power(&self.n, p, *e);
+            roots=roots.iter()
+                .flat_map(|x|local_roots.iter().map(|r|ModN::chinese(vec![x,&ring.new(r.clone())]).unwrap()).collect::<Vec<_>>())
+                .collect();
+        }
+        let mut roots:Vec<ModN>=roots.into_iter().map(|x|ModN::new(x.n,self.modulus.clone())).collect();
+        roots.sort();
+        roots
+    }
+}
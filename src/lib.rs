@@ -6,22 +6,33 @@
 #[macro_use]
 pub mod integers;
 pub mod intmod;
+pub mod error;
 pub mod field;
+pub mod ring;
+pub mod exponentiation;
 pub mod univariate;
 pub mod multivariate;
 pub mod continued_functions;
+pub mod rational;
 
 #[cfg(test)]
 mod tests {
  
     use num_bigint::BigInt;
+    use num_traits::One;
     use crate::intmod::PrimeField;
     use crate::multivariate::multiindex::MultiIndex;
     use crate::multivariate::terms::Terms;
     use crate::{integers::IntUtilities, intmod::Mod};
+    use crate::field::Field;
+    use crate::intmod::modn::{IntegerModRing,ModN,Garner};
     use crate::univariate::poly::Poly;
     use crate::poly;
-    use crate::intmod::MathError::QuadraticNonResidueModP;
+    use crate::intmod::MathError::{self,QuadraticNonResidueModP};
+    use crate::integers::Factorization;
+    use crate::integers::factor::{factor, pollard_rho, pollard_pm1, ecm};
+    use crate::integers::siqs::siqs;
+    use crate::integers::primes::{primes, primes_up_to, random_prime, random_safe_prime, random_prime_congruent};
 #[test]
 fn test_gcd() {
     let a=BigInt::from(60u8);
@@ -36,6 +47,15 @@ fn test_even_part() {
     assert_eq!(BigInt::even_part(&a),expected_value);
 }
 #[test]
+fn test_gcd_against_euclid(){
+    let euclid=|mut a:i64,mut b:i64|->i64{ while b!=0 {let r=a%b; a=b; b=r;} a.abs() };
+    for a in -60i64..60 {
+        for b in -60i64..60 {
+            assert_eq!(BigInt::gcd(BigInt::from(a),BigInt::from(b)),BigInt::from(euclid(a,b)),"gcd({},{})",a,b);
+        }
+    }
+}
+#[test]
 fn test_gcdext() {
     let a=BigInt::from(24u32);
     let b=BigInt::from(60u32);
@@ -44,10 +64,25 @@ fn test_gcdext() {
     assert_eq!(&bezout[0]*a+&bezout[1]*b,bezout[2]);
 }
 #[test]
+fn test_gcdext_even_remainder() {
+    // the first Euclidean step leaves the even remainder 68
+    let a=BigInt::from(18_446_744_073_709_551_359u128);
+    let b=BigInt::from(18_446_744_073_709_551_427u128);
+    let bezout=BigInt::gcdext(a.clone(), b.clone());
+    assert_eq!(&bezout[0]*a+&bezout[1]*b,BigInt::one());
+    for x in 1..200u32 {
+        for y in 1..200u32 {
+            let bezout=BigInt::gcdext(BigInt::from(x), BigInt::from(y));
+            assert_eq!(&bezout[0]*x+&bezout[1]*y,bezout[2]);
+            assert_eq!(bezout[2],BigInt::gcd(BigInt::from(x), BigInt::from(y)));
+        }
+    }
+}
+#[test]
 fn test_chinese() {
     let a=Mod::new(BigInt::from(8),PrimeField(BigInt::from(11)));
     let b=Mod::new(BigInt::from(4),PrimeField(BigInt::from(13)));
-    let expected_mod=Some(Mod::new(BigInt::from(30),PrimeField(BigInt::from(143))));
+    let expected_mod=Some(ModN::new(BigInt::from(30),IntegerModRing(BigInt::from(143))));
     assert_eq!(expected_mod,Mod::chinese(vec![&a,&b]));
 
 }
@@ -105,4 +140,885 @@ fn monomial_division_should_panic(){
     let _ = &mut mono1/&mut mono2;
 
 }
-}
\ No newline at end of file
+#[test]
+fn test_is_prime_small_range(){
+    let naive=|n:u64|->bool{ n>1 && (2..n).take_while(|d|d*d<=n).all(|d|n%d!=0) };
+    for n in 0u64..20000 {
+        assert_eq!(naive(n),BigInt::is_prime(&BigInt::from(n)),"failed for {}",n);
+    }
+}
+#[test]
+fn test_is_prime_pseudoprimes(){
+    // Carmichael numbers and strong pseudoprimes to several bases
+    let composites=[561u64,1105,1729,2047,3215031751,3825123056546413051];
+    for c in composites.iter() {
+        assert!(!BigInt::is_prime(&BigInt::from(*c)));
+    }
+    let big_composite="318665857834031151167461".parse::<BigInt>().unwrap();
+    assert!(!BigInt::is_prime(&big_composite));
+    assert!(!BigInt::baillie_psw(&big_composite));
+    assert!(BigInt::is_strong_lucas_probable_prime(&BigInt::from(5459)));
+    assert!(!BigInt::baillie_psw(&BigInt::from(5459)));
+}
+#[test]
+fn test_is_prime_large(){
+    let m127=(BigInt::one()<<127)-BigInt::one();
+    let m128=(BigInt::one()<<128)-BigInt::one();
+    assert!(BigInt::is_prime(&m127));
+    assert!(BigInt::miller_rabin(&m127,25));
+    assert!(!BigInt::is_prime(&m128));
+    assert!(!BigInt::miller_rabin(&m128,25));
+    assert!(!BigInt::is_prime(&(&m127*&m127)));
+}
+#[test]
+fn test_prime_field_try_new(){
+    assert_eq!(PrimeField::try_new(BigInt::from(17)),Ok(PrimeField(BigInt::from(17))));
+    for n in [-7i64,0,1,4,91,561] {
+        assert_eq!(PrimeField::try_new(BigInt::from(n)),Err(MathError::NonPrimeModulus(BigInt::from(n))));
+    }
+    let m127:BigInt=(BigInt::one()<<127)-BigInt::one();
+    assert!(PrimeField::try_new(m127.clone()).is_ok());
+    assert!(PrimeField::try_new(m127+BigInt::from(2)).is_err());
+}
+#[test]
+fn test_multiplicative_order_brute_force(){
+    for p in [2u64,3,5,7,13,17,41,97,101] {
+        let field=PrimeField::try_new(BigInt::from(p)).unwrap();
+        let mut generators=0;
+        for a in 1..p {
+            let x=field.new(BigInt::from(a));
+            let mut power=x.clone();
+            let mut order=1u64;
+            while !power.is_one() {power=&power*&x; order+=1;}
+            assert_eq!(x.multiplicative_order(),Some(BigInt::from(order)));
+            assert_eq!(x.is_primitive_root(),order==p-1);
+            if order==p-1 {generators+=1;}
+        }
+        let phi=BigInt::euler_phi(&BigInt::from(p-1));
+        assert_eq!(BigInt::from(generators),phi);
+        assert!(field.primitive_root().is_primitive_root());
+        assert!(field.random_primitive_root().is_primitive_root());
+    }
+}
+#[test]
+fn test_roots_of_unity(){
+    let p=BigInt::from(998_244_353u64);
+    let field=PrimeField::try_new(p).unwrap();
+    assert_eq!(field.primitive_root(),field.new(BigInt::from(3)));
+    let omega=field.root_of_unity(&BigInt::from(1u64<<23)).unwrap();
+    assert_eq!(omega.multiplicative_order(),Some(BigInt::from(1u64<<23)));
+    assert!(field.root_of_unity(&BigInt::from(1u64<<24)).is_none());
+}
+#[test]
+fn test_discrete_log_brute_force(){
+    for p in [2u64,3,5,7,13,31] {
+        let field=PrimeField::try_new(BigInt::from(p)).unwrap();
+        for b in 1..p {
+            let base=field.new(BigInt::from(b));
+            let mut power=field.one();
+            let mut logs=vec![None;p as usize];
+            for x in 0..p-1 {
+                let index=usize::try_from(&power.n).unwrap();
+                if logs[index].is_none() {logs[index]=Some(BigInt::from(x));}
+                power=&power*&base;
+            }
+            for t in 1..p {
+                let target=field.new(BigInt::from(t));
+                assert_eq!(Mod::discrete_log(&base,&target),logs[t as usize]);
+            }
+        }
+    }
+}
+#[test]
+fn test_discrete_log_large(){
+    use rand::SeedableRng;
+    use crate::integers::primes::random_safe_prime;
+    let mut rng=rand::rngs::StdRng::seed_from_u64(7);
+    // the subgroup of squares has prime order q of 33 bits: solved by Pollard rho
+    let p=random_safe_prime(34,&mut rng);
+    let field=PrimeField::try_new(p.clone()).unwrap();
+    let base=field.new(BigInt::from(4));
+    let x=BigInt::from(123_456_789_012u64)%(&p>>1);
+    let target=base.clone().pow_mod(&x);
+    assert_eq!(Mod::discrete_log(&base,&target),Some(x));
+    // p-1 smooth: Pohlig-Hellman with baby-step giant-step on each factor
+    let p=BigInt::from(2u64).pow(61)-BigInt::one();
+    let field=PrimeField::try_new(p.clone()).unwrap();
+    let base=field.primitive_root();
+    let x=BigInt::from(987_654_321_987_654_321u64);
+    let target=base.clone().pow_mod(&x);
+    assert_eq!(Mod::discrete_log(&base,&target),Some(x));
+}
+#[test]
+fn test_discrete_log_modulus_mismatch(){
+    use crate::intmod::dlog::{try_pohlig_hellman, try_baby_step_giant_step, try_pollard_rho_log};
+    let (z1019,z2039)=(PrimeField(BigInt::from(1019)),PrimeField(BigInt::from(2039)));
+    let (base,target)=(z2039.new(BigInt::from(4)),z1019.new(BigInt::from(4)));
+    let order=BigInt::from(1019);
+    assert_eq!(Mod::try_discrete_log(&base,&target),Err(MathError::ModulusMismatch));
+    assert_eq!(try_pohlig_hellman(&base,&target,&Factorization::new(&order)),Err(MathError::ModulusMismatch));
+    assert_eq!(try_baby_step_giant_step(&base,&target,&order),Err(MathError::ModulusMismatch));
+    assert_eq!(try_pollard_rho_log(&base,&target,&order),Err(MathError::ModulusMismatch));
+    assert_eq!(try_pollard_rho_log(&base,&base.clone().pow_mod(&BigInt::from(777)),&order),Ok(Some(BigInt::from(777))));
+}
+#[test]
+#[should_panic(expected = "Elements of different rings!")]
+fn discrete_log_modulus_mismatch_should_panic(){
+    Mod::discrete_log(&PrimeField(BigInt::from(1019)).one(),&PrimeField(BigInt::from(2039)).one());
+}
+#[test]
+fn test_integer_mod_ring(){
+    let z12=IntegerModRing(BigInt::from(12));
+    let mut units=0;
+    for a in 0..12 {
+        let x=z12.new(BigInt::from(a));
+        for b in 0..12 {
+            let y=z12.new(BigInt::from(b));
+            assert_eq!(&x+&y,z12.new(BigInt::from(a+b)));
+            assert_eq!(&x-&y,z12.new(BigInt::from(a-b)));
+            assert_eq!(&x*&y,z12.new(BigInt::from(a*b)));
+        }
+        assert_eq!(&x+&(-&x),z12.zero());
+        match x.try_inverse() {
+            Ok(inverse)=>{
+                assert!(x.is_unit());
+                assert!((&x*&inverse).is_one());
+                units+=1;
+            },
+            Err(MathError::NonInvertibleElement(d))=>{
+                assert!(!x.is_unit());
+                assert_eq!(d,BigInt::gcd(BigInt::from(a),BigInt::from(12)));
+            },
+            Err(error)=>panic!("{:?}",error)
+        }
+    }
+    assert_eq!(BigInt::from(units),z12.unit_group_order());
+    let n=BigInt::from(1_000_000_007u64)*BigInt::from(998_244_353u64);
+    let zn=IntegerModRing(n.clone());
+    let x=zn.new(BigInt::from(123_456_789_123_456_789u64));
+    assert_eq!(ModN::chinese(x.crt_components().iter().collect()),Some(x.clone()));
+    assert_eq!(x.pow(&BigInt::from(-1)),x.try_inverse().unwrap());
+    assert_eq!(ModN::from(PrimeField(BigInt::from(13)).new(BigInt::from(5))),IntegerModRing(BigInt::from(13)).new(BigInt::from(5)));
+}
+#[test]
+fn test_generalized_chinese_brute_force(){
+    for m in 1..=12i64 {
+        for n in 1..=12i64 {
+            let lcm=m*n/i64::try_from(BigInt::gcd(BigInt::from(m),BigInt::from(n))).unwrap();
+            for a in 0..m {
+                for b in 0..n {
+                    let x=IntegerModRing(BigInt::from(m)).new(BigInt::from(a));
+                    let y=IntegerModRing(BigInt::from(n)).new(BigInt::from(b));
+                    let expected=(0..lcm).find(|z|z%m==a && z%n==b)
+                        .map(|z|IntegerModRing(BigInt::from(lcm)).new(BigInt::from(z)));
+                    assert_eq!(ModN::chinese(vec![&x,&y]),expected);
+                }
+            }
+        }
+    }
+    let f=Mod::new(BigInt::from(3),PrimeField(BigInt::from(5)));
+    let g=Mod::new(BigInt::from(4),PrimeField(BigInt::from(5)));
+    assert_eq!(Mod::chinese(vec![&f,&f]),Some(ModN::from(f.clone())));
+    assert_eq!(Mod::chinese(vec![&f,&g]),None);
+}
+#[test]
+fn test_garner(){
+    let moduli:Vec<BigInt>=[1_000_000_007u64,998_244_353,1_000_000_009,65_537].iter().map(|m|BigInt::from(*m)).collect();
+    let garner=Garner::new(&moduli).unwrap();
+    let ring=garner.modulus().clone();
+    for _ in 0..20 {
+        let x=ring.random();
+        let residues:Vec<BigInt>=moduli.iter().map(|m|&x.n%m).collect();
+        let digits=garner.mixed_radix(&residues);
+        assert!(digits.iter().zip(&moduli).all(|(v,m)|v<m));
+        assert_eq!(garner.reconstruct(&residues),x);
+        let components:Vec<ModN>=moduli.iter().map(|m|IntegerModRing(m.clone()).new(x.n.clone())).collect();
+        assert_eq!(ModN::chinese(components.iter().collect()),Some(x));
+    }
+}
+#[test]
+fn test_sqrt_mod_prime_power_brute_force(){
+    use crate::intmod::sqrt::sqrt_mod_prime_power;
+    for (p,max_k) in [(2u64,7u32),(3,4),(5,3),(7,3),(11,2)] {
+        for k in 1..=max_k {
+            let modulus=p.pow(k);
+            for a in 0..modulus {
+                let expected:Vec<BigInt>=(0..modulus).filter(|x|x*x%modulus==a).map(BigInt::from).collect();
+                assert_eq!(sqrt_mod_prime_power(&BigInt::from(a),&BigInt::from(p),k),expected);
+            }
+        }
+    }
+}
+#[test]
+fn test_sqrt_mod_composite_brute_force(){
+    for n in 1..=150u64 {
+        let ring=IntegerModRing(BigInt::from(n));
+        let factorization=ring.factorization();
+        for a in 0..n {
+            let expected:Vec<ModN>=(0..n).filter(|x|x*x%n==a).map(|x|ring.new(BigInt::from(x))).collect();
+            assert_eq!(ring.new(BigInt::from(a)).sqrts_with(&factorization),expected);
+        }
+    }
+    // Rabin with 64-bit primes p,q = 3 mod 4
+    let p=BigInt::from(18_446_744_073_709_551_427u128);
+    let q=BigInt::from(18_446_744_073_709_551_359u128);
+    let ring=IntegerModRing(&p*&q);
+    let m=ring.new(BigInt::from(123_456_789_987_654_321u64));
+    let roots=(&m*&m).sqrts_with(&crate::integers::Factorization(vec![(q,1),(p,1)]));
+    assert_eq!(roots.len(),4);
+    assert!(roots.contains(&m) && roots.contains(&-&m));
+}
+#[test]
+fn test_nth_roots_brute_force(){
+    for p in [2u64,3,7,13,31,37] {
+        let field=PrimeField::try_new(BigInt::from(p)).unwrap();
+        for n in 1..=12u64 {
+            let n_big=BigInt::from(n);
+            let powers:Vec<Mod>=(0..p).map(|y|field.new(BigInt::from(y)).pow_mod(&n_big)).collect();
+            for a in 0..p {
+                let x=field.new(BigInt::from(a));
+                let expected:Vec<Mod>=(0..p).filter(|y|powers[*y as usize]==x)
+                    .map(|y|field.new(BigInt::from(y))).collect();
+                assert_eq!(x.is_nth_power(&n_big),!expected.is_empty());
+                assert_eq!(x.all_nth_roots(&n_big),expected);
+                match x.nth_root(&n_big) {
+                    Some(root)=>assert!(expected.contains(&root)),
+                    None=>assert!(expected.is_empty())
+                }
+            }
+        }
+    }
+}
+#[test]
+fn test_nth_roots_large(){
+    // p-1 = 3*2^30: the 2-adic valuation is 30, so there are roots of unity of order 2^k for every k<=30
+    let p=BigInt::from(3u64)*(BigInt::one()<<30)+BigInt::one();
+    let field=PrimeField::try_new(p).unwrap();
+    let x=field.new(BigInt::from(123_456_789u64));
+    for n in [2u64,3,8,9,64,3u64.pow(4)*2,1u64<<20] {
+        let n=BigInt::from(n);
+        let power=x.clone().pow_mod(&n);
+        let mut root=power.nth_root(&n).unwrap();
+        assert_eq!(root.pow_mod(&n),power);
+    }
+    let cube=x.clone().pow_mod(&BigInt::from(3));
+    assert_eq!(cube.all_nth_roots(&BigInt::from(3)).len(),3);
+}
+#[test]
+fn test_sqrt_algorithms_brute_force(){
+    for p in crate::integers::primes::primes_up_to(200).into_iter().skip(1) {
+        let field=PrimeField::try_new(BigInt::from(p)).unwrap();
+        for a in 0..p {
+            let x=field.new(BigInt::from(a));
+            let expected=(0..p).map(|y|field.new(BigInt::from(y))).find(|y|y*y==x);
+            let expected=expected.ok_or(QuadraticNonResidueModP);
+            assert_eq!(x.clone().check_sqrt_mod_prime(),expected);
+            assert_eq!(x.sqrt_tonelli_shanks(),expected);
+            assert_eq!(x.sqrt_cipolla(),expected);
+            match p%8 {
+                3|7=>assert_eq!(x.sqrt_3_mod_4(),expected),
+                5=>assert_eq!(x.sqrt_atkin(),expected),
+                _=>{}
+            }
+        }
+    }
+}
+#[test]
+fn test_sqrt_algorithms_large(){
+    let m127:BigInt=(BigInt::one()<<127)-BigInt::one();
+    let p25519:BigInt=(BigInt::one()<<255)-BigInt::from(19);
+    // a prime with p-1 divisible by 2^64
+    let mut p_2adic:BigInt=(BigInt::one()<<64)+BigInt::one();
+    while !BigInt::is_prime(&p_2adic) {p_2adic+=BigInt::one()<<64;}
+    for p in [m127,p25519,p_2adic] {
+        let field=PrimeField::try_new(p).unwrap();
+        for k in 1..4u32 {
+            let x=field.new(BigInt::from(k).pow(20)+BigInt::from(7));
+            let square=&x*&x;
+            let root=square.clone().check_sqrt_mod_prime().unwrap();
+            assert!(root==x || root==-x.clone());
+            assert_eq!(square.sqrt_tonelli_shanks(),Ok(root.clone()));
+            assert_eq!(square.sqrt_cipolla(),Ok(root));
+            let non_residue=&square*&field.quadratic_non_residue();
+            assert_eq!(non_residue.clone().check_sqrt_mod_prime(),Err(QuadraticNonResidueModP));
+        }
+    }
+}
+#[test]
+fn test_montgomery_against_mod(){
+    use crate::intmod::montgomery::{MontgomeryField,MontgomeryMod};
+    let primes=[BigInt::from(3),BigInt::from(1_000_000_007u64),(BigInt::one()<<127)-BigInt::one(),(BigInt::one()<<255)-BigInt::from(19)];
+    for p in primes {
+        let field=PrimeField::try_new(p.clone()).unwrap();
+        let montgomery=MontgomeryField::from_prime_field(&field);
+        for _ in 0..50 {
+            let (x,y)=(field.random(),field.random());
+            let (mx,my)=(MontgomeryMod::from_mod(&x,&montgomery),MontgomeryMod::from_mod(&y,&montgomery));
+            assert_eq!(mx.to_mod(),x);
+            assert_eq!((&mx+&my).to_mod(),&x+&y);
+            assert_eq!((&mx-&my).to_mod(),&x-&y);
+            assert_eq!((&mx*&my).to_mod(),&x*&y);
+            assert_eq!((-mx.clone()).to_mod(),-x.clone());
+            let exponent=BigInt::from(65_537)*&y.n;
+            assert_eq!(mx.pow(&exponent).to_mod(),x.clone().pow_mod(&exponent));
+            if !y.is_zero() {
+                assert_eq!((&mx/&my).to_mod(),&x/&y);
+                assert!((&my*&my.inverse()).is_one());
+            }
+            assert_eq!(mx.cmp(&my),x.cmp(&y));
+        }
+        assert_eq!(montgomery.new(-BigInt::one()).to_mod(),field.new(p-BigInt::one()));
+    }
+}
+#[test]
+fn test_montgomery_polynomials(){
+    use crate::intmod::montgomery::MontgomeryField;
+    let z13=PrimeField(BigInt::from(13));
+    let field=MontgomeryField::from_prime_field(&z13);
+    let p1=Poly::new_from_coeffs(&[field.new(BigInt::from(1)), field.zero(), field.new(BigInt::from(10)),field.new(BigInt::from(2))]);
+    let p2=Poly::new_from_coeffs(&[field.new(BigInt::from(1)),field.zero(),-field.one()]);
+    let bezout=Poly::gcdext(&p1,&p2);
+    assert_eq!(&p1*&bezout[0]+&p2*&bezout[1],bezout[2]);
+    let expected_gcd=Poly::new_from_coeffs(&[field.new(BigInt::from(11)),field.new(BigInt::from(2))]);
+    assert_eq!(bezout[2],expected_gcd);
+}
+#[test]
+fn test_fp64_against_mod(){
+    use crate::intmod::fp64::{Fp64Field,Fp64};
+    for p in [2u64,3,1_000_000_007,(1<<61)-1,9_223_372_036_854_775_783] {
+        let field=PrimeField::try_new(BigInt::from(p)).unwrap();
+        let small=Fp64Field::from_prime_field(&field).unwrap();
+        for _ in 0..200 {
+            let (x,y)=(field.random(),field.random());
+            let (sx,sy)=(Fp64::from_mod(&x,&small),Fp64::from_mod(&y,&small));
+            assert_eq!(sx.to_mod(),x);
+            assert_eq!((sx+sy).to_mod(),&x+&y);
+            assert_eq!((sx-sy).to_mod(),&x-&y);
+            assert_eq!((sx*sy).to_mod(),&x*&y);
+            assert_eq!((-sx).to_mod(),-x.clone());
+            let exponent=y.n.to_u64_digits().1.first().copied().unwrap_or(0);
+            assert_eq!(sx.pow(exponent).to_mod(),x.clone().pow_mod(&BigInt::from(exponent)));
+            if !y.is_zero() {
+                assert_eq!((sx/sy).to_mod(),&x/&y);
+                assert!((sy*sy.inverse()).is_one());
+            }
+            assert_eq!(sx.cmp(&sy),x.cmp(&y));
+        }
+        assert_eq!(small.from_bigint(&-BigInt::one()).value(),p-1);
+    }
+    assert_eq!(Fp64Field::try_new(561),Err(MathError::NonPrimeModulus(BigInt::from(561))));
+    // 2^63+29 is prime but does not fit
+    assert_eq!(Fp64Field::try_new((1<<63)+29),Err(MathError::ModulusTooLarge(BigInt::from((1u64<<63)+29))));
+    let large=PrimeField::try_new(BigInt::from((1u64<<63)+29)).unwrap();
+    assert_eq!(Fp64Field::try_from(&large),Err(MathError::ModulusTooLarge(large.0.clone())));
+    // the order is the one of Mod, across fields too
+    let (x,y)=(Fp64Field::try_new(13).unwrap().new(5),Fp64Field::try_new(7).unwrap().new(3));
+    assert_eq!(x.cmp(&y),x.to_mod().cmp(&y.to_mod()));
+}
+#[test]
+fn test_fp64_polynomials(){
+    use crate::intmod::fp64::Fp64Field;
+    use crate::univariate::polymod::Modulus;
+    use crate::multivariate::multivariatepoly::MultivariatePoly;
+    let field=Fp64Field::try_new(13).unwrap();
+    let p1=Poly::new_from_coeffs(&[field.new(1), field.zero(), field.new(10),field.new(2)]);
+    let p2=Poly::new_from_coeffs(&[field.new(1),field.zero(),-field.one()]);
+    let bezout=Poly::gcdext(&p1,&p2);
+    assert_eq!(&p1*&bezout[0]+&p2*&bezout[1],bezout[2]);
+    assert_eq!(bezout[2],Poly::new_from_coeffs(&[field.new(11),field.new(2)]));
+    // x^2 = -1 in F_13[x]/(x^2+1)
+    let modulus=Modulus(Poly::new_from_coeffs(&[field.one(),field.zero(),field.one()]));
+    let x=modulus.new(Poly::new_from_coeffs(&[field.one(),field.zero()]));
+    assert_eq!((x.clone()*x).poly,Poly::new_from_coeffs(&[-field.one()]));
+    // (x+y)^2 = x^2+2xy+y^2
+    let x=MultivariatePoly::new(vec![Terms::new(field.one(),MultiIndex::new(&vec![1,0]))]);
+    let y=MultivariatePoly::new(vec![Terms::new(field.one(),MultiIndex::new(&vec![0,1]))]);
+    let square=(x.clone()+y.clone())*(x+y);
+    let expected=MultivariatePoly::new(vec![
+        Terms::new(field.one(),MultiIndex::new(&vec![2,0])),
+        Terms::new(field.new(2),MultiIndex::new(&vec![1,1])),
+        Terms::new(field.one(),MultiIndex::new(&vec![0,2]))]);
+    assert_eq!(square,expected);
+}
+fn check_fp_against_mod<const P:u64>(){
+    use crate::intmod::fp::Fp;
+    let field=Fp::<P>::prime_field();
+    for _ in 0..200 {
+        let (x,y)=(field.random(),field.random());
+        let (fx,fy)=(Fp::<P>::from_mod(&x),Fp::<P>::from_mod(&y));
+        assert_eq!(fx.to_mod(),x);
+        assert_eq!((fx+fy).to_mod(),&x+&y);
+        assert_eq!((fx-fy).to_mod(),&x-&y);
+        assert_eq!((fx*fy).to_mod(),&x*&y);
+        assert_eq!((-fx).to_mod(),-x.clone());
+        let exponent=y.n.to_u64_digits().1.first().copied().unwrap_or(0);
+        assert_eq!(fx.pow(exponent).to_mod(),x.clone().pow_mod(&BigInt::from(exponent)));
+        if !y.is_zero() {
+            assert_eq!((fx/fy).to_mod(),&x/&y);
+            assert!((fy*fy.inverse()).is_one());
+        }
+        assert_eq!(fx.cmp(&fy),x.cmp(&y));
+        assert_eq!(fx.to_fp64().value(),fx.value());
+    }
+    assert_eq!(Fp::<P>::from_bigint(&-BigInt::one()).value(),P-1);
+}
+#[test]
+fn test_fp_against_mod(){
+    check_fp_against_mod::<2>();
+    check_fp_against_mod::<13>();
+    check_fp_against_mod::<1_000_000_007>();
+    check_fp_against_mod::<{(1<<61)-1}>();
+    check_fp_against_mod::<9_223_372_036_854_775_783>();
+}
+#[test]
+fn test_fp_polynomials(){
+    use crate::intmod::fp::Fp;
+    use crate::multivariate::multivariatepoly::MultivariatePoly;
+    type F13=Fp<13>;
+    let p1=Poly::new_from_coeffs(&[F13::new(1), F13::zero(), F13::new(10),F13::new(2)]);
+    let p2=Poly::new_from_coeffs(&[F13::new(1),F13::zero(),-F13::one()]);
+    let bezout=Poly::gcdext(&p1,&p2);
+    assert_eq!(&p1*&bezout[0]+&p2*&bezout[1],bezout[2]);
+    assert_eq!(bezout[2],Poly::new_from_coeffs(&[F13::new(11),F13::new(2)]));
+    // (x-y)*(x+y) = x^2-y^2
+    let x=MultivariatePoly::new(vec![Terms::new(F13::one(),MultiIndex::new(&vec![1,0]))]);
+    let y=MultivariatePoly::new(vec![Terms::new(F13::one(),MultiIndex::new(&vec![0,1]))]);
+    let product=(x.clone()-y.clone())*(x+y);
+    let expected=MultivariatePoly::new(vec![
+        Terms::new(F13::one(),MultiIndex::new(&vec![2,0])),
+        Terms::new(-F13::one(),MultiIndex::new(&vec![0,2]))]);
+    assert_eq!(product,expected);
+}
+#[test]
+fn test_rational_polynomials(){
+    use crate::rational::Rational;
+    let q=|n:i64,d:i64|Rational::new(BigInt::from(n),BigInt::from(d));
+    // 2x^3-x+1/3 = (x^2-1/2)(2x)+1/3
+    let f=Poly::new_from_coeffs(&[q(2,1),q(0,1),q(-1,1),q(1,3)]);
+    let g=Poly::new_from_coeffs(&[q(1,1),q(0,1),q(-1,2)]);
+    let quotient_remainder=Poly::div_rem(&f,&g);
+    assert_eq!(quotient_remainder[0],Poly::new_from_coeffs(&[q(2,1),q(0,1)]));
+    assert_eq!(quotient_remainder[1],Poly::new_from_coeffs(&[q(1,3)]));
+    assert_eq!(f.evaluate(&q(1,2)),q(1,12));
+    assert_eq!(g.to_string(),"1*x^2-1/2");
+    assert_eq!((&g-&g).to_string(),"0");
+    let bezout=Poly::gcdext(&f,&g);
+    assert_eq!(&(&f*&bezout[0])+&(&g*&bezout[1]),bezout[2]);
+    assert!(bezout[2].is_constant());
+}
+#[test]
+fn test_rational_multivariate_division(){
+    use crate::rational::Rational;
+    use crate::multivariate::multivariatepoly::MultivariatePoly;
+    let q=|n:i64,d:i64|Rational::new(BigInt::from(n),BigInt::from(d));
+    let term=|c:Rational,e:Vec<usize>|Terms::new(c,MultiIndex::new(&e));
+    // x^2y+1/2xy^2+y^2 divided by xy-1 and y^2-1/3 [Cox, Little, O'Shea, chapter 2, section 3]
+    let mut f=MultivariatePoly::new(vec![term(q(1,1),vec![2,1]),term(q(1,2),vec![1,2]),term(q(1,1),vec![0,2])]);
+    let f1=MultivariatePoly::new(vec![term(q(1,1),vec![1,1]),term(q(-1,1),vec![0])]);
+    let f2=MultivariatePoly::new(vec![term(q(1,1),vec![0,2]),term(q(-1,3),vec![0])]);
+    let (quotients,remainder)=f.multi_division_reminder(vec![f1.clone(),f2.clone()]);
+    let recombined=quotients[0].clone()*f1+quotients[1].clone()*f2+remainder.clone();
+    assert!((recombined-f).is_zero());
+    assert_eq!(remainder,MultivariatePoly::new(vec![term(q(1,1),vec![1,0]),term(q(1,2),vec![0,1]),term(q(1,3),vec![0])]));
+}
+#[test]
+fn test_ring_gcdext_integers(){
+    use num_traits::Signed;
+    use crate::ring::{self, EuclideanDomain};
+    let values=[BigInt::from(0),BigInt::from(1),BigInt::from(-7),BigInt::from(60),BigInt::from(24),BigInt::from(1001),BigInt::from(-4864),BigInt::from(3458)];
+    for a in &values {
+        for b in &values {
+            let [u,v,d]=ring::gcdext(a,b);
+            assert_eq!(a*&u+b*&v,d);
+            assert_eq!(ring::gcd(a,b),d);
+            if !(a==&BigInt::from(0)&&b==&BigInt::from(0)) {
+                assert_eq!(BigInt::gcd(a.clone(),b.clone()),d);
+            }
+            // Euclidean division: 0 <= r < |b|
+            if b!=&BigInt::from(0) {
+                let (q,r)=EuclideanDomain::div_rem(a,b);
+                assert_eq!(&q*b+&r,*a);
+                assert!(r>=BigInt::from(0) && &r<&BigInt::abs(b));
+            }
+        }
+    }
+}
+#[test]
+fn test_ring_chinese_remainder(){
+    use crate::ring::chinese_remainder;
+    let pairs=[(BigInt::from(1),BigInt::from(3)),(BigInt::from(2),BigInt::from(5)),(BigInt::from(4),BigInt::from(7))];
+    let (x,m)=chinese_remainder(&pairs).unwrap();
+    assert_eq!(m,BigInt::from(105));
+    for (r,n) in &pairs {assert_eq!(BigInt::modulo(&(&x-r),n),BigInt::from(0));}
+    // moduli 4 and 6 are not coprime: x = 3 mod 4 and x = 1 mod 6 gives x = 7 mod 12
+    assert_eq!(chinese_remainder(&[(BigInt::from(3),BigInt::from(4)),(BigInt::from(1),BigInt::from(6))]),Some((BigInt::from(7),BigInt::from(12))));
+    assert_eq!(chinese_remainder(&[(BigInt::from(0),BigInt::from(4)),(BigInt::from(1),BigInt::from(6))]),None);
+    assert_eq!(chinese_remainder::<BigInt>(&[]),None);
+    // the truncated remainder would give x=-5, and negative moduli a negative lcm
+    assert_eq!(chinese_remainder(&[(BigInt::from(1),BigInt::from(3)),(BigInt::from(0),BigInt::from(5))]),Some((BigInt::from(10),BigInt::from(15))));
+    assert_eq!(chinese_remainder(&[(BigInt::from(-1),BigInt::from(-3)),(BigInt::from(0),BigInt::from(-5))]),Some((BigInt::from(5),BigInt::from(15))));
+    assert_eq!(chinese_remainder(&[(BigInt::from(-8),BigInt::from(7))]),Some((BigInt::from(6),BigInt::from(7))));
+}
+#[test]
+fn test_integer_polynomials(){
+    let n=BigInt::from;
+    // polynomials over Z, no division needed
+    let f=poly!(n(1),n(-1));
+    let g=poly!(n(1),n(1));
+    let product=&f*&g;
+    assert_eq!(product,poly!(n(1),n(0),n(-1)));
+    assert_eq!(product.to_string(),"1*x^2-1");
+    assert_eq!(product.evaluate(&n(5)),n(24));
+    assert_eq!((&product-&(&f*&f)).to_string(),"2*x-2");
+    // the image of the product in Z/nZ is the product of the images
+    let z7=IntegerModRing(n(7));
+    let reduce=|p:&Poly<BigInt>|Poly::new_from_coeffs(&p.coeffs.iter().map(|c|z7.new(c.clone())).collect::<Vec<ModN>>());
+    assert_eq!(reduce(&product),&reduce(&f)*&reduce(&g));
+}
+#[test]
+fn test_fallible_operations(){
+    use crate::error::AlgebraError;
+    use crate::univariate::polymod::Modulus;
+    use crate::multivariate::multivariatepoly::MultivariatePoly;
+    use crate::rational::Rational;
+    let z13=PrimeField(BigInt::from(13));
+    let z17=PrimeField(BigInt::from(17));
+    let (x,y)=(z13.new(BigInt::from(5)),z17.new(BigInt::from(5)));
+    assert_eq!(x.checked_add(&y),Err(AlgebraError::ModulusMismatch));
+    assert_eq!(x.checked_sub(&y),Err(AlgebraError::ModulusMismatch));
+    assert_eq!(x.checked_mul(&x),Ok(z13.new(BigInt::from(12))));
+    assert_eq!(x.checked_div(&z13.zero()),Err(AlgebraError::DivisionByZero));
+    assert_eq!(Rational::from(0).try_inverse(),Err(AlgebraError::DivisionByZero));
+    let (a,b)=(IntegerModRing(BigInt::from(6)).one(),IntegerModRing(BigInt::from(10)).one());
+    assert_eq!(a.checked_mul(&b),Err(AlgebraError::ModulusMismatch));
+    assert_eq!(b.try_div(&IntegerModRing(BigInt::from(10)).new(BigInt::from(4))),Err(AlgebraError::NonInvertibleElement(BigInt::from(2))));
+    // polynomials
+    let f=poly!(z13.one(),z13.one());
+    assert_eq!(Poly::try_div_rem(&f,&f.zero()),Err(AlgebraError::DivisionByZero));
+    let p=Modulus(poly!(z13.one(),z13.zero(),z13.one())).new(f.clone());
+    let q=Modulus(poly!(z13.one(),z13.zero(),z13.zero(),z13.one())).new(f.clone());
+    assert_eq!(p.checked_mul(&q),Err(AlgebraError::ModulusMismatch));
+    assert_eq!(q.try_inverse(),Err(AlgebraError::NonInvertiblePolynomial));
+    assert!((p.clone()*p.try_inverse().unwrap()).is_one());
+    // multivariate
+    let t1=Terms::new(z13.one(),MultiIndex::new(&vec![1,0]));
+    let t2=Terms::new(z13.one(),MultiIndex::new(&vec![0,1]));
+    assert_eq!(t1.checked_add(&t2),Err(AlgebraError::DifferentMonomials));
+    let g=MultivariatePoly::new(vec![t1.clone(),t2.clone()]);
+    let mut zero=g.zero();
+    assert_eq!(g.try_div_rem(&mut zero),Err(AlgebraError::DivisionByZero));
+    assert_eq!(MultiIndex::new(&vec![1,0]).checked_sub(&mut MultiIndex::new(&vec![0,1])),Err(AlgebraError::NegativeExponent));
+    // errors are std errors, and can be propagated with ?
+    let checked=||->Result<Mod,Box<dyn std::error::Error>>{Ok(x.checked_add(&y)?)};
+    assert_eq!(checked().unwrap_err().to_string(),"Elements of different rings!");
+}
+#[test]
+#[should_panic(expected = "Elements of different rings!")]
+fn mod_mismatch_should_panic(){
+    let _=PrimeField(BigInt::from(13)).one()+PrimeField(BigInt::from(17)).one();
+}
+#[test]
+fn test_batch_inverse(){
+    use crate::field::{batch_inverse, try_batch_inverse};
+    use crate::error::AlgebraError;
+    use crate::rational::Rational;
+    let field=PrimeField::try_new((BigInt::one()<<127)-1).unwrap();
+    let elements:Vec<Mod>=(0..50).map(|_|field.random()).filter(|x|!x.is_zero()).collect();
+    let inverses=batch_inverse(&elements);
+    assert_eq!(inverses,elements.iter().map(|x|x.inverse()).collect::<Vec<Mod>>());
+    assert_eq!(batch_inverse::<Mod>(&[]),vec![]);
+    let rationals=[Rational::from(3),Rational::from(-2)];
+    assert_eq!(batch_inverse(&rationals),vec![Rational::new(BigInt::from(1),BigInt::from(3)),Rational::new(BigInt::from(-1),BigInt::from(2))]);
+    assert_eq!(try_batch_inverse(&[Rational::from(3),Rational::from(0)]),Err(AlgebraError::DivisionByZero));
+    // the error is the one of the first element that is not invertible
+    let z15=PrimeField(BigInt::from(15));
+    let elements=[z15.new(BigInt::from(2)),z15.new(BigInt::from(10)),z15.new(BigInt::from(3))];
+    assert_eq!(try_batch_inverse(&elements),Err(MathError::NonInvertibleElement(BigInt::from(5))));
+}
+#[test]
+fn test_multi_pow(){
+    use num_bigint::RandBigInt;
+    let naive=|bases:&[Mod],exps:&[BigInt]|bases.iter().zip(exps).fold(bases[0].one(),|acc,(g,e)|acc*g.clone().pow_mod(e));
+    let mut rng=rand::thread_rng();
+    for p in [BigInt::from(1009),(BigInt::one()<<127)-1] {
+        let field=PrimeField::try_new(p.clone()).unwrap();
+        for k in [1usize,2,3,5,17,40] {
+            let bases:Vec<Mod>=(0..k).map(|_|field.new(rng.gen_bigint_range(&BigInt::one(),&p))).collect();
+            let exps:Vec<BigInt>=bases.iter().map(|_|rng.gen_bigint_range(&-&p,&p)).collect();
+            let expected=naive(&bases,&exps);
+            assert_eq!(Mod::multi_pow(&bases,&exps),expected);
+            assert_eq!(Mod::multi_pow_straus(&bases,&exps),expected);
+            assert_eq!(Mod::multi_pow_pippenger(&bases,&exps),expected);
+        }
+    }
+    let z13=PrimeField(BigInt::from(13));
+    let bases=[z13.new(BigInt::from(2)),z13.new(BigInt::from(5))];
+    let zeros=[BigInt::from(0),BigInt::from(0)];
+    assert!(Mod::multi_pow_straus(&bases,&zeros).is_one());
+    assert!(Mod::multi_pow_pippenger(&bases,&zeros).is_one());
+}
+#[test]
+fn test_exponentiation_engine(){
+    use num_bigint::RandBigInt;
+    use crate::exponentiation::{pow_sliding_window, pow_ladder, FixedBase};
+    let mut rng=rand::thread_rng();
+    let p:BigInt=(BigInt::one()<<127)-1;
+    let field=PrimeField::try_new(p.clone()).unwrap();
+    let g=field.random();
+    let table=FixedBase::new(&g,127,4);
+    // exponents of every length cover all the window sizes
+    for bits in [0u64,1,5,23,24,80,127,240,700] {
+        let exp:BigInt=rng.gen_biguint(bits).into();
+        let expected=Mod::new(g.n.modpow(&exp,&p),field.clone());
+        assert_eq!(pow_sliding_window(&g,&exp),expected);
+        assert_eq!(pow_ladder(&g,&exp,bits.max(1)),expected);
+        assert_eq!(g.pow_mod_ladder(&exp),expected);
+        assert_eq!(g.clone().pow_mod(&exp),expected);
+        assert_eq!(table.pow(&exp),expected);
+    }
+    let exp=rng.gen_bigint_range(&-&p,&BigInt::from(0));
+    assert_eq!(g.clone().pow_mod(&exp)*g.clone().pow_mod(&-&exp),field.new(BigInt::one()));
+    assert_eq!(g.pow_mod_ladder(&exp),g.clone().pow_mod(&exp));
+}
+#[test]
+fn test_constant_time_against_mod(){
+    use crate::intmod::ct::{CtField,CtMod};
+    fn check<const L:usize>(p:BigInt){
+        let field=PrimeField::try_new(p.clone()).unwrap();
+        let ct=CtField::<L>::from_prime_field(&field);
+        for _ in 0..50 {
+            let (x,y)=(field.random(),field.random());
+            let (cx,cy)=(CtMod::from_mod(&x,&ct),CtMod::from_mod(&y,&ct));
+            assert_eq!(cx.to_mod(),x);
+            assert_eq!((&cx+&cy).to_mod(),&x+&y);
+            assert_eq!((&cx-&cy).to_mod(),&x-&y);
+            assert_eq!((&cx*&cy).to_mod(),&x*&y);
+            assert_eq!((-cx.clone()).to_mod(),-x.clone());
+            assert_eq!(cx.pow(&y.n).to_mod(),x.clone().pow_mod(&y.n));
+            assert_eq!(cx==cy,x==y);
+            assert_eq!(CtMod::select(&cx,&cy,true),cy);
+            let (mut a,mut b)=(cx.clone(),cy.clone());
+            CtMod::conditional_swap(&mut a,&mut b,false);
+            assert_eq!((&a,&b),(&cx,&cy));
+            CtMod::conditional_swap(&mut a,&mut b,true);
+            assert_eq!((&a,&b),(&cy,&cx));
+            if !y.is_zero() {
+                assert_eq!((&cx/&cy).to_mod(),&x/&y);
+                assert!((&cy*&cy.inverse()).is_one());
+            }
+        }
+        assert_eq!(ct.new(-BigInt::one()).to_mod(),field.new(&p-BigInt::one()));
+        assert_eq!(ct.zero().try_inverse(),Err(MathError::DivisionByZero));
+    }
+    check::<1>(BigInt::from(3));
+    // above 2^63 the Montgomery product needs its extra carry word
+    check::<1>(BigInt::from(18_446_744_073_709_551_557u64));
+    check::<2>((BigInt::one()<<127)-BigInt::one());
+    check::<4>((BigInt::one()<<255)-BigInt::from(19));
+    check::<5>((BigInt::one()<<255)-BigInt::from(19));
+    let (z3,z5)=(PrimeField(BigInt::from(3)),PrimeField(BigInt::from(5)));
+    let (ct3,ct5)=(CtField::<1>::from_prime_field(&z3),CtField::<1>::from_prime_field(&z5));
+    assert!(ct3.new(BigInt::from(2))>ct5.new(BigInt::one()));
+    assert!(ct3.one()<ct5.one());
+    assert_eq!(CtField::<0>::try_from_prime_field(&z3),Err(MathError::ModulusTooLarge(BigInt::from(3))));
+}
+#[test]
+#[should_panic(expected = "Division by zero!")]
+fn constant_time_zero_inverse_should_panic(){
+    use crate::intmod::ct::CtField;
+    let ct=CtField::<1>::from_prime_field(&PrimeField(BigInt::from(101)));
+    ct.zero().inverse();
+}
+#[test]
+fn test_quadratic_residues_mod_p(){
+    use crate::integers::QuadraticCharacter;
+    let primes=[BigInt::from(2),BigInt::from(1_000_000_007u64),(BigInt::one()<<127)-BigInt::one(),(BigInt::one()<<255)-BigInt::from(19)];
+    for p in primes {
+        let field=PrimeField::try_new(p.clone()).unwrap();
+        let exp=(&p-BigInt::one())>>1;
+        for _ in 0..100 {
+            let x=field.random();
+            // Euler's criterion
+            let euler=x.clone().pow_mod(&exp);
+            let expected=if x.is_zero() {QuadraticCharacter::Zero} else if euler.is_one() {QuadraticCharacter::Residue} else {QuadraticCharacter::NonResidue};
+            assert_eq!(x.quadratic_character(),expected);
+            assert!((&x*&x).is_zero() || (&x*&x).is_quadratic_residue());
+            assert_eq!(x.is_quadratic_residue(),x.clone().check_sqrt_mod_prime().is_ok() && !x.is_zero());
+        }
+        assert!(!field.zero().is_quadratic_residue());
+        assert!(field.one().is_quadratic_residue());
+    }
+}
+#[test]
+fn test_factor_small(){
+    for n in 1u64..3000 {
+        let factors=factor(&BigInt::from(n));
+        let product=factors.iter().fold(BigInt::one(),|acc,(p,e)|acc*p.pow(*e));
+        assert_eq!(product,BigInt::from(n));
+        assert!(factors.iter().all(|(p,_)|BigInt::is_prime(p)));
+        assert!(factors.windows(2).all(|w|w[0].0<w[1].0));
+    }
+}
+#[test]
+fn test_factor_large(){
+    let p1=BigInt::from(1_000_000_007u64);
+    let p2=BigInt::from(998_244_353u64);
+    let p3:BigInt="2305843009213693951".parse().unwrap();
+    let n=&p1*&p1*&p1*&p2*&p3*BigInt::from(12);
+    let expected=vec![(BigInt::from(2),2),(BigInt::from(3),1),(p2,1),(p1,3),(p3,1)];
+    assert_eq!(factor(&n),expected);
+    assert_eq!(factor(&-BigInt::from(12)),vec![(BigInt::from(2),2),(BigInt::from(3),1)]);
+}
+#[test]
+fn test_factor_methods(){
+    let p=BigInt::from(1_451_521u64);
+    let q=BigInt::from(2_000_003u64);
+    let n=&p*&q;
+    let d=pollard_rho(&n,1<<20).unwrap();
+    assert!(d==p||d==q);
+    assert_eq!(pollard_pm1(&n,1000),Some(p.clone()));
+    let d=ecm(&n,2000,100).unwrap();
+    assert!(d==p||d==q);
+}
+#[test]
+fn test_factor_methods_edge_cases(){
+    // with b1 below 2D=210 the factors are mostly found in stage 2
+    let (p,q)=(BigInt::from(1_000_003u64),BigInt::from(1_000_033u64));
+    let d=ecm(&(&p*&q),50,30).unwrap();
+    assert!(d==p||d==q);
+    for n in 0u64..8 {
+        let expected=[4u64,6].contains(&n).then(||BigInt::from(2));
+        assert_eq!(ecm(&BigInt::from(n),100,5),expected);
+        assert_eq!(pollard_rho(&BigInt::from(n),100),expected);
+    }
+}
+#[test]
+fn test_siqs(){
+    let p:BigInt="1000000000000000003".parse().unwrap();
+    let q:BigInt="33333222255555559".parse().unwrap();
+    let n=&p*&q;
+    let d=siqs(&n).unwrap();
+    assert!(d==p||d==q);
+    // primes and prime powers have no factor to find, small or large
+    assert_eq!(siqs(&BigInt::from(1_000_000_007u64)),None);
+    assert_eq!(siqs(&p),None);
+    assert_eq!(siqs(&(&p*&p)),None);
+    assert_eq!(siqs(&BigInt::from(3u64.pow(40))),None);
+}
+#[test]
+fn test_primes_iterator(){
+    let sieved=primes_up_to(70_000);
+    let naive:Vec<u64>=(0..=70_000u64).filter(|n|BigInt::is_prime(&BigInt::from(*n))).collect();
+    assert_eq!(sieved,naive);
+    // crosses several segments
+    assert_eq!(primes().nth(100_000),Some(1_299_721));
+}
+#[test]
+fn test_next_prev_prime(){
+    let mut p=BigInt::from(1);
+    for q in primes().take(2000) {
+        let q=BigInt::from(q);
+        assert_eq!(BigInt::next_prime(&p),q);
+        if q>BigInt::from(2) {assert_eq!(BigInt::prev_prime(&q),Some(p.clone()));}
+        p=q;
+    }
+    let m127:BigInt=(BigInt::one()<<127)-BigInt::one();
+    assert_eq!(BigInt::prev_prime(&(&m127+BigInt::one())),Some(m127.clone()));
+    assert_eq!(BigInt::next_prime(&(&m127-BigInt::one())),m127);
+}
+#[test]
+fn test_random_primes_are_reproducible(){
+    use rand::SeedableRng;
+    let mut rng1=rand::rngs::StdRng::seed_from_u64(2024);
+    let mut rng2=rand::rngs::StdRng::seed_from_u64(2024);
+    for bits in [2u64,3,8,17,64,200] {
+        let p=random_prime(bits,&mut rng1);
+        assert_eq!(p.bits(),bits);
+        assert!(BigInt::is_prime(&p));
+        assert_eq!(p,random_prime(bits,&mut rng2));
+    }
+    let p=random_safe_prime(96,&mut rng1);
+    assert_eq!(p.bits(),96);
+    assert!(BigInt::is_prime(&p)&&BigInt::is_prime(&(&p>>1)));
+    let m=BigInt::from(1_000_003);
+    let p=random_prime_congruent(80,&BigInt::from(-1),&m,&mut rng1).unwrap();
+    assert_eq!(&p%&m,&m-BigInt::one());
+    assert_eq!(p.bits(),80);
+    // small residue classes are enumerated
+    let p=random_prime_congruent(8,&BigInt::from(1),&BigInt::from(10),&mut rng1).unwrap();
+    assert!([131,151,181,191,211,241,251].contains(&p.to_string().parse::<u32>().unwrap()));
+}
+#[test]
+fn test_multiplicative_functions_brute_force(){
+    let gcd=|mut a:u64,mut b:u64|->u64{ while b!=0 {let r=a%b; a=b; b=r;} a };
+    let pow_mod=|a:u64,e:u64,m:u64|->u64{ (0..e).fold(1%m,|acc,_|acc*a%m) };
+    for n in 1u64..300 {
+        let f=Factorization::new(&BigInt::from(n));
+        let divisors:Vec<u64>=(1..=n).filter(|d|n%d==0).collect();
+        let units:Vec<u64>=(1..=n).filter(|a|gcd(*a,n)==1).collect();
+        let lambda=(1..=n).find(|k|units.iter().all(|a|pow_mod(*a,*k,n)==1%n)).unwrap();
+        let squarefree=(2..=n).take_while(|d|d*d<=n).all(|d|n%(d*d)!=0);
+        let omega=(2..=n).filter(|p|n%p==0 && BigInt::is_prime(&BigInt::from(*p))).count();
+        let mobius= if !squarefree {0} else if omega%2==0 {1} else {-1};
+        let perfect_power=(2..=n).any(|m|(2..64).any(|k|m.checked_pow(k)==Some(n)));
+        assert_eq!(f.euler_phi(),BigInt::from(units.len()),"phi({})",n);
+        assert_eq!(f.carmichael_lambda(),BigInt::from(lambda),"lambda({})",n);
+        assert_eq!(f.mobius(),mobius,"mu({})",n);
+        assert_eq!(f.number_of_divisors(),BigInt::from(divisors.len()),"tau({})",n);
+        for k in 0..4u32 {
+            let sigma:u64=divisors.iter().map(|d|d.pow(k)).sum();
+            assert_eq!(f.sigma(k),BigInt::from(sigma),"sigma_{}({})",k,n);
+        }
+        assert_eq!(f.divisors(),divisors.iter().map(|d|BigInt::from(*d)).collect::<Vec<BigInt>>());
+        assert_eq!(f.is_squarefree(),squarefree);
+        assert_eq!(BigInt::is_perfect_power(&BigInt::from(n)),perfect_power,"perfect power {}",n);
+        assert_eq!(f.value(),BigInt::from(n));
+    }
+    assert_eq!(BigInt::euler_phi(&BigInt::from(1_000_000_007u64*3)),BigInt::from(2_000_000_012u64));
+    assert_eq!(BigInt::mobius(&BigInt::from(30)),-1);
+}
+#[test]
+fn test_quadratic_symbols_against_euler(){
+    use num_traits::Zero;
+    use crate::integers::QuadraticCharacter;
+    for p in primes_up_to(200).into_iter().skip(1) {
+        let squares:std::collections::BTreeSet<BigInt>=(1..p).map(|x|BigInt::from(x*x%p)).collect();
+        let p=&BigInt::from(p);
+        for a in -250i64..250 {
+            let a=BigInt::from(a);
+            // Euler's criterion: a^((p-1)/2) = (a/p) mod p
+            let euler=BigInt::modulo(&a, p).modpow(&((p-1)>>1), p);
+            let expected=if euler.is_zero() {0} else if euler.is_one() {1} else {-1};
+            let legendre=BigInt::legendre(&a, p);
+            assert_eq!(i8::from(legendre),expected,"({}/{})",a,p);
+            assert_eq!(legendre.is_residue(),squares.contains(&BigInt::modulo(&a, p)));
+            assert_eq!(QuadraticCharacter::from_symbol(expected),legendre);
+        }
+    }
+    // the Jacobi symbol is multiplicative in the modulus, and the Kronecker symbol extends it
+    for n in (1i64..300).step_by(2) {
+        let factors=Factorization::new(&BigInt::from(n));
+        for a in -60i64..60 {
+            let a=BigInt::from(a);
+            let expected=factors.0.iter().map(|(p,e)|i8::from(BigInt::legendre(&a, p)).pow(*e)).product::<i8>();
+            assert_eq!(BigInt::jacobi(&a, &BigInt::from(n)),expected,"({}/{})",a,n);
+            assert_eq!(BigInt::kronecker(&a, &BigInt::from(n)),expected);
+        }
+    }
+    // (a/2) depends on a mod 8, and (a/-1) on the sign of a
+    for a in -40i64..40 {
+        let two=match a.rem_euclid(8) {1|7=>1,3|5=>-1,_=>0};
+        assert_eq!(BigInt::kronecker(&BigInt::from(a), &BigInt::from(2)),two);
+        assert_eq!(BigInt::kronecker(&BigInt::from(a), &BigInt::from(-1)),if a<0 {-1} else {1});
+        for b in -40i64..40 {
+            let (a,b)=(BigInt::from(a),BigInt::from(b));
+            assert_eq!(BigInt::kronecker(&a, &b)==0,BigInt::gcd(a.clone(), b.clone())!=BigInt::one());
+        }
+    }
+}
+#[test]
+#[should_panic(expected = "odd positive modulus")]
+fn jacobi_even_modulus_should_panic(){
+    BigInt::jacobi(&BigInt::from(3), &BigInt::from(10));
+}
+}
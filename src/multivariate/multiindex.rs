@@ -1,4 +1,5 @@
 use std::ops::{Add,Sub};
+use crate::error::AlgebraError;
 
 #[derive(Clone,PartialEq, Eq,PartialOrd,Ord,Default,Debug)]
 pub struct MultiIndex(pub Vec< usize>);
@@ -43,6 +44,23 @@ impl MultiIndex {
         let matching = self_resize.0.iter().zip(&rhs_resize.0).filter(|&(a, b)| a <= b).count();
         matching==self_resize.len()
     }    
+/// Componentwise difference, or `AlgebraError::NegativeExponent` if some exponent of rhs
+/// exceeds the one of self.
+///
+/// # Example
+/// ```
+/// use algebra::multivariate::multiindex::MultiIndex;
+/// use algebra::error::AlgebraError;
+/// let mut alpha=MultiIndex::new(&vec![2,1]);
+/// assert_eq!(alpha.checked_sub(&mut MultiIndex::new(&vec![1,1])),Ok(MultiIndex::new(&vec![1])));
+/// assert_eq!(alpha.checked_sub(&mut MultiIndex::new(&vec![0,2])),Err(AlgebraError::NegativeExponent));
+/// ```
+    pub fn checked_sub(&mut self,rhs:&mut Self)->Result<MultiIndex,AlgebraError>{
+        if !rhs.is_subtractable_by(self) {return Err(AlgebraError::NegativeExponent);}
+        let (self_resize,rhs_resize)=self.resize(rhs);
+        let sub:Vec<usize>=self_resize.0.iter().zip(rhs_resize.0.iter()).map(|(a,b)|a-b).collect();
+        Ok(MultiIndex::new(&sub))
+    }
 }
 impl<'a, 'b> Add<&'b mut MultiIndex> for &'a mut MultiIndex {
     type Output = MultiIndex;
@@ -87,14 +105,7 @@ impl<'a, 'b> Sub<&'b mut MultiIndex> for &'a mut MultiIndex {
 ///  
 /// ```
     fn sub(self:&'a mut MultiIndex, rhs: &'b mut MultiIndex) -> Self::Output {
-        if !rhs.is_subtractable_by(self) {panic!("Cant subtract {:?} from {:?}",self,rhs)}
-        let (self_resize,rhs_resize)=self.resize(rhs);
-        let mut sub=Vec::new();
-        for (a, b) in self_resize.0.iter().zip(rhs_resize.0.iter()) {
-            sub.push(a - b);
-        }
-        MultiIndex::new(&sub)
-        
+        self.checked_sub(rhs).unwrap_or_else(|error|panic!("{}",error))
     }
 }
 
@@ -6,6 +6,7 @@
 use core::fmt;
 use std::{collections::BinaryHeap, fmt::Display, ops::{Add, Div, Mul, Neg, Rem, Sub}};
 use crate::field::Field;
+use crate::error::AlgebraError;
 use super::terms::Terms;
 
 #[derive(Clone, PartialEq,Eq, Default,Debug)]
@@ -61,8 +62,12 @@ impl <F:Field>MultivariatePoly<F> {
         self.is_empty()||*self==self.zero()||self.leading_term().is_zero()
     }
     pub fn div_rem (&self, rhs:&mut Self) -> (MultivariatePoly<F>,MultivariatePoly<F> ){
+        self.try_div_rem(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
+/// Quotient and remainder of the division by rhs, or `AlgebraError::DivisionByZero` if rhs is zero.
+    pub fn try_div_rem (&self, rhs:&mut Self) -> Result<(MultivariatePoly<F>,MultivariatePoly<F>),AlgebraError>{
 
-        if rhs.is_zero(){panic!("Cannot divide by zero poly!")}
+        if rhs.is_zero(){return Err(AlgebraError::DivisionByZero)}
         let mut q=MultivariatePoly::zero(&self);
         let mut r = MultivariatePoly::zero(&self);
         let mut lt=(&(self-&(&mut q*rhs))-&r).leading_term();
@@ -78,7 +83,7 @@ impl <F:Field>MultivariatePoly<F> {
             if x.is_zero(){break;}
             lt =x.leading_term();
         }
-        (q,r)
+        Ok((q,r))
     }
 /// # Example
 /// This algorithm express a multivariate polynomial f in the form
@@ -1,6 +1,7 @@
 use std::{fmt::{self, Display}, iter::Sum, ops::{Add, Div, Mul, Neg}};
 
 use crate::field::Field;
+use crate::error::AlgebraError;
 
 use super::multiindex::MultiIndex;
 
@@ -56,6 +57,34 @@ impl <F:Field>Terms<F> {
         rhs.multi_index.is_subtractable_by(&mut self.multi_index)
 
     }
+/// Sum of two terms with the same monomial, or `AlgebraError::DifferentMonomials`.
+    pub fn checked_add(&self,rhs:&Self)->Result<Self,AlgebraError>{
+        if self.is_zero(){return Ok(rhs.clone());}
+        if rhs.is_zero(){return Ok(self.clone());}
+        if self.multi_index!=rhs.multi_index {return Err(AlgebraError::DifferentMonomials);}
+        Ok(Terms::new(self.coeff.clone()+rhs.coeff.clone(), self.multi_index.clone()))
+    }
+/// Quotient of two terms, or `AlgebraError::NotDivisible` if the monomial of rhs does not divide
+/// the one of self.
+///
+/// # Example
+/// ```
+/// use algebra::intmod::PrimeField;
+/// use algebra::error::AlgebraError;
+/// use num_bigint::BigInt;
+/// use algebra::multivariate::terms::Terms;
+/// use algebra::multivariate::multiindex::MultiIndex;
+/// let z13=PrimeField(BigInt::from(13));
+/// let mut term1=Terms::new(z13.new(BigInt::from(3)),MultiIndex::new(&vec![2,0]));
+/// let mut term2=Terms::new(z13.new(BigInt::from(4)),MultiIndex::new(&vec![1,0,1]));
+/// assert_eq!(term1.try_div(&mut term2),Err(AlgebraError::NotDivisible));
+/// ```
+    pub fn try_div(&mut self,rhs:&mut Self)->Result<Self,AlgebraError>{
+        if !self.is_divisible_by(rhs) {return Err(AlgebraError::NotDivisible);}
+        let coeff=self.coeff.clone()*rhs.coeff.try_inverse()?;
+        let multi_index=self.multi_index.checked_sub(&mut rhs.multi_index)?;
+        Ok(Terms::new(coeff,multi_index))
+    }
     pub fn number_of_variables(&self)->usize{
         self.multi_index.0.iter().filter(|a|**a!=0usize).count()
     }
@@ -89,12 +118,7 @@ impl <'a, F:Field> Sum for &'a Terms<F> {
 impl<'a, 'b,F:Field> Add<&'b Terms<F>>for &'a Terms<F> {
     type Output = Terms<F>;
     fn add(self, rhs: &'b Terms<F>) -> Self::Output {
-        if self.is_zero(){return rhs.clone();}
-        if rhs.is_zero(){return self.clone();}
-        if self.multi_index==rhs.multi_index {
-            let coeff=self.coeff.clone()+rhs.coeff.clone();
-            Terms::new(coeff, self.multi_index.clone())
-        } else {panic!("Cannot add {} and {}. No similar monomial!",self,rhs)}
+        self.checked_add(rhs).unwrap_or_else(|error|panic!("{}",error))
         }
     }
     impl <'a,F:Field> Neg for &'a Terms<F> {
@@ -151,10 +175,6 @@ impl<'a, 'b,F:Field> Div<&'b mut Terms<F>> for &'a mut Terms<F> {
 /// 
 /// ```
     fn div(self:&'a mut Terms<F>, rhs: &'b mut Terms<F>) -> Self::Output {    
-        if !self.is_divisible_by(rhs) {panic!("Cannot divide!")}
-        let coeff=self.coeff.clone()*rhs.coeff.inverse();
-        let multi_index=&mut self.multi_index-&mut rhs.multi_index;
-        
-        Terms::new(coeff,multi_index)
+        self.try_div(rhs).unwrap_or_else(|error|panic!("{}",error))
         }
 }
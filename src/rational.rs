@@ -0,0 +1,189 @@
+//! The field Q of rational numbers.
+//! [`Rational`] wraps [`BigRational`], always in lowest terms with a positive denominator, and
+//! implements [`Field`], so that `Poly<Rational>` and `MultivariatePoly<Rational>` do exact arithmetic
+//! over Q. It displays as `p/q`, or `p` when the denominator is 1, and negative coefficients
+//! are printed with their sign, so that [`Poly::print_poly`](crate::univariate::poly::Poly::print_poly)
+//! writes polynomials as `1*x^2-1/2*x+3`.
+//!
+//! # Example
+//! ```
+//! #[macro_use] extern crate algebra;
+//! use algebra::rational::Rational;
+//! use algebra::univariate::poly::Poly;
+//! use num_bigint::BigInt;
+//! let half=Rational::new(BigInt::from(1),BigInt::from(2));
+//! let f=poly!(Rational::from(1),-half.clone(),Rational::from(3));
+//! assert_eq!(f.to_string(),"1*x^2-1/2*x+3");
+//! // (x-1/2)(x+2) and (x-1/2)(x-3) have gcd x-1/2, up to a constant
+//! let g=&poly!(Rational::from(1),-half.clone())*&poly!(Rational::from(1),Rational::from(2));
+//! let h=&poly!(Rational::from(1),-half.clone())*&poly!(Rational::from(1),Rational::from(-3));
+//! let gcd=Poly::gcdext(&g,&h)[2].clone();
+//! assert_eq!(gcd.deg(),1);
+//! assert_eq!(&gcd.coeffs[1]/&gcd.coeffs[0],-half);
+//! ```
+use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Zero, Signed};
+use crate::field::Field;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rational(pub BigRational);
+impl Rational {
+/// The fraction num/den in lowest terms. It panics if den is zero.
+///
+/// # Example
+/// ```
+/// use algebra::rational::Rational;
+/// use num_bigint::BigInt;
+/// let x=Rational::new(BigInt::from(6),BigInt::from(-4));
+/// assert_eq!(x.to_string(),"-3/2");
+/// assert_eq!((&x+&Rational::from(2)).to_string(),"1/2");
+/// ```
+    pub fn new(num:BigInt,den:BigInt)->Rational{
+        if den.is_zero() {panic!("Denominator is zero!")}
+        Rational(BigRational::new(num, den))
+    }
+    pub fn from_integer(n:BigInt)->Rational{
+        Rational(BigRational::from_integer(n))
+    }
+    pub fn numer(&self)->&BigInt{
+        self.0.numer()
+    }
+    pub fn denom(&self)->&BigInt{
+        self.0.denom()
+    }
+    pub fn is_integer(&self)->bool{
+        self.0.is_integer()
+    }
+    pub fn abs(&self)->Rational{
+        Rational(self.0.abs())
+    }
+/// Computes self^exp, negative exponents are allowed for non-zero elements.
+    pub fn pow(&self,exp:i32)->Rational{
+        if exp<0 && self.is_zero() {panic!("Not an invertible element!")}
+        Rational(num_traits::Pow::pow(&self.0, exp))
+    }
+}
+impl From<BigRational> for Rational {
+    fn from(x: BigRational) -> Self {
+        Rational(x)
+    }
+}
+impl From<Rational> for BigRational {
+    fn from(x: Rational) -> Self {
+        x.0
+    }
+}
+impl From<BigInt> for Rational {
+    fn from(n: BigInt) -> Self {
+        Rational::from_integer(n)
+    }
+}
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational::from_integer(BigInt::from(n))
+    }
+}
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl<'a> Add<&'a Rational> for &'a Rational {
+    type Output = Rational;
+    fn add(self, rhs: Self) -> Self::Output {
+        Rational(&self.0+&rhs.0)
+    }
+}
+impl<'a> Sub<&'a Rational> for &'a Rational {
+    type Output = Rational;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Rational(&self.0-&rhs.0)
+    }
+}
+impl<'a> Mul<&'a Rational> for &'a Rational {
+    type Output = Rational;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Rational(&self.0*&rhs.0)
+    }
+}
+impl<'a> Div<&'a Rational> for &'a Rational {
+    type Output = Rational;
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {panic!("Not an invertible element!")}
+        Rational(&self.0/&rhs.0)
+    }
+}
+impl Neg for &Rational {
+    type Output = Rational;
+    fn neg(self) -> Self::Output {
+        Rational(-&self.0)
+    }
+}
+impl Add<Rational> for Rational {
+    type Output = Rational;
+    fn add(self, rhs: Self) -> Self::Output {
+        Rational(self.0+rhs.0)
+    }
+}
+impl<'a> Add<&'a Rational> for Rational {
+    type Output = Rational;
+    fn add(self, rhs: &'a Rational) -> Self::Output {
+        Rational(self.0+&rhs.0)
+    }
+}
+impl<'a> Add<&'a mut Rational> for Rational {
+    type Output = Rational;
+    fn add(self, rhs: &'a mut Rational) -> Self::Output {
+        Rational(self.0+&rhs.0)
+    }
+}
+impl Sub<Rational> for Rational {
+    type Output = Rational;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Rational(self.0-rhs.0)
+    }
+}
+impl Mul<Rational> for Rational {
+    type Output = Rational;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Rational(self.0*rhs.0)
+    }
+}
+impl<'a> Mul<&'a Rational> for Rational {
+    type Output = Rational;
+    fn mul(self, rhs: &'a Rational) -> Self::Output {
+        Rational(self.0*&rhs.0)
+    }
+}
+impl Div<Rational> for Rational {
+    type Output = Rational;
+    fn div(self, rhs: Self) -> Self::Output {
+        &self/&rhs
+    }
+}
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Self::Output {
+        Rational(-self.0)
+    }
+}
+impl Field for Rational {
+    fn one(&self)->Self {
+        Rational(BigRational::one())
+    }
+    fn zero(&self)->Self {
+        Rational(BigRational::zero())
+    }
+    fn is_zero(&self)->bool {
+        self.0.is_zero()
+    }
+    fn is_one(&self)->bool {
+        self.0.is_one()
+    }
+    fn inverse(&self)->Self {
+        if self.is_zero() {panic!("Not an invertible element!")}
+        Rational(self.0.recip())
+    }
+}
@@ -0,0 +1,237 @@
+//! The hierarchy of algebraic structures below [`Field`]:
+//! [`Ring`] ⊃ [`CommutativeRing`] ⊃ [`IntegralDomain`] ⊃ [`EuclideanDomain`] ⊃ [`Field`].
+//! Every [`Field`] is a Euclidean domain through the blanket implementations of this module, with
+//! exact division and zero remainder. The integers and the polynomials over a field are Euclidean
+//! domains with the usual division with remainder, Z/nZ and F[x]/(m) are commutative rings.
+//! Since [`Poly`] only asks its coefficients to form a ring, `Poly<BigInt>` and `Poly<Poly<F>>` are
+//! polynomials over Z and over F[y].
+//!
+//! The extended Euclidean algorithm and the Chinese remainder theorem are written once here,
+//! for every Euclidean domain.
+//!
+//! # Example
+//! ```
+//! #[macro_use] extern crate algebra;
+//! use algebra::ring::{gcdext, chinese_remainder};
+//! use algebra::intmod::PrimeField;
+//! use num_bigint::BigInt;
+//! let [u,v,d]=gcdext(&BigInt::from(240),&BigInt::from(46));
+//! assert_eq!(d,BigInt::from(2));
+//! assert_eq!(BigInt::from(240)*u+BigInt::from(46)*v,d);
+//! // x = 2 mod 3, x = 3 mod 5
+//! let crt=chinese_remainder(&[(BigInt::from(2),BigInt::from(3)),(BigInt::from(3),BigInt::from(5))]);
+//! assert_eq!(crt,Some((BigInt::from(8),BigInt::from(15))));
+//! // the same algorithm over F_13[x]
+//! let z13=PrimeField(BigInt::from(13));
+//! let f=poly!(z13.new(BigInt::from(1)),z13.new(BigInt::from(0)),z13.new(BigInt::from(12)));
+//! let g=poly!(z13.new(BigInt::from(1)),z13.new(BigInt::from(12)));
+//! assert_eq!(gcdext(&f,&g)[2].deg(),1);
+//! ```
+use std::{ops::{Add, Mul, Neg, Sub}, fmt::{Display, Debug}};
+use num_bigint::BigInt;
+use num_traits::Signed;
+use crate::field::Field;
+use crate::intmod::modn::ModN;
+use crate::univariate::{poly::Poly, polymod::PolyMod};
+
+/// A ring with identity. Elements know their own zero and one, as in [`Field`],
+/// so that rings whose elements carry a modulus need no global constants.
+pub trait Ring
+where
+    Self:Sized
+        +Debug
+        +Clone
+        +Display
+        +PartialEq
+        +Eq
+        + Add<Self, Output = Self>
+        +for<'a> Add<&'a Self,Output = Self>
+        + Sub<Self, Output = Self>
+        + Mul<Self, Output = Self>
+        +for<'a> Mul<&'a Self,Output = Self>
+        + Neg<Output = Self>,
+{
+    fn zero(&self)->Self;
+    fn one(&self)->Self;
+    fn is_zero(&self)->bool;
+    fn is_one(&self)->bool;
+}
+/// A ring whose multiplication is commutative.
+pub trait CommutativeRing: Ring {}
+/// A commutative ring without zero divisors.
+pub trait IntegralDomain: CommutativeRing {}
+/// An integral domain with a division with remainder: `a.div_rem(b)` returns (q,r) with a=q*b+r
+/// and r smaller than b, in absolute value for the integers and in degree for polynomials.
+/// It panics if b is zero.
+pub trait EuclideanDomain: IntegralDomain {
+    fn div_rem(&self,rhs:&Self)->(Self,Self);
+/// The unit u such that u*self is the preferred one among the associates of self, e.g. the
+/// non-negative one for the integers. [`gcd`], [`gcdext`] and [`chinese_remainder`] normalize
+/// their results with it. The default is one, which leaves the results as computed.
+    fn normalizing_unit(&self)->Self{
+        self.one()
+    }
+}
+impl<F:Field> Ring for F {
+    fn zero(&self)->Self {
+        Field::zero(self)
+    }
+    fn one(&self)->Self {
+        Field::one(self)
+    }
+    fn is_zero(&self)->bool {
+        Field::is_zero(self)
+    }
+    fn is_one(&self)->bool {
+        Field::is_one(self)
+    }
+}
+impl<F:Field> CommutativeRing for F {}
+impl<F:Field> IntegralDomain for F {}
+impl<F:Field> EuclideanDomain for F {
+    fn div_rem(&self,rhs:&Self)->(Self,Self) {
+        (self.clone()/rhs.clone(),Field::zero(self))
+    }
+}
+impl Ring for BigInt {
+    fn zero(&self)->Self {
+        <BigInt as num_traits::Zero>::zero()
+    }
+    fn one(&self)->Self {
+        <BigInt as num_traits::One>::one()
+    }
+    fn is_zero(&self)->bool {
+        num_traits::Zero::is_zero(self)
+    }
+    fn is_one(&self)->bool {
+        num_traits::One::is_one(self)
+    }
+}
+impl CommutativeRing for BigInt {}
+impl IntegralDomain for BigInt {}
+/// Euclidean division, the remainder lies in [0,|rhs|).
+impl EuclideanDomain for BigInt {
+    fn div_rem(&self,rhs:&Self)->(Self,Self) {
+        let (mut q,mut r)=(self/rhs,self%rhs);
+        if r.is_negative() {
+            if rhs.is_negative() {q+=1; r-=rhs;} else {q-=1; r+=rhs;}
+        }
+        (q,r)
+    }
+    fn normalizing_unit(&self)->Self {
+        if self.is_negative() {-<BigInt as num_traits::One>::one()} else {<BigInt as num_traits::One>::one()}
+    }
+}
+impl Ring for ModN {
+    fn zero(&self)->Self {
+        self.modulus.zero()
+    }
+    fn one(&self)->Self {
+        self.modulus.one()
+    }
+    fn is_zero(&self)->bool {
+        ModN::is_zero(self)
+    }
+    fn is_one(&self)->bool {
+        ModN::is_one(self)
+    }
+}
+impl CommutativeRing for ModN {}
+impl<R:Ring> Ring for Poly<R> {
+    fn zero(&self)->Self {
+        Poly::zero(self)
+    }
+    fn one(&self)->Self {
+        Poly::one(self)
+    }
+    fn is_zero(&self)->bool {
+        Poly::is_zero(self)
+    }
+    fn is_one(&self)->bool {
+        Poly::is_one(self)
+    }
+}
+impl<R:CommutativeRing> CommutativeRing for Poly<R> {}
+impl<R:IntegralDomain> IntegralDomain for Poly<R> {}
+impl<F:Field> EuclideanDomain for Poly<F> {
+    fn div_rem(&self,rhs:&Self)->(Self,Self) {
+        let [q,r]:[Poly<F>;2]=Poly::div_rem(self, rhs).try_into().unwrap();
+        (q,r)
+    }
+}
+impl<F:Field> Ring for PolyMod<F> {
+    fn zero(&self)->Self {
+        PolyMod::zero(self)
+    }
+    fn one(&self)->Self {
+        PolyMod::one(self)
+    }
+    fn is_zero(&self)->bool {
+        PolyMod::is_zero(self)
+    }
+    fn is_one(&self)->bool {
+        PolyMod::is_one(self)
+    }
+}
+impl<F:Field> CommutativeRing for PolyMod<F> {}
+/// Greatest common divisor, normalized by [`EuclideanDomain::normalizing_unit`]: non-negative for
+/// the integers.
+pub fn gcd<E:EuclideanDomain>(a:&E,b:&E)->E{
+    let (mut a,mut b)=(a.clone(),b.clone());
+    while !b.is_zero() {
+        let r=a.div_rem(&b).1;
+        a=b;
+        b=r;
+    }
+    a.normalizing_unit()*&a
+}
+/// Extended Euclidean algorithm [Algorithm 1.3.6 H.Cohen A course in computational number theory]:
+/// returns [u,v,d] with a*u+b*v=d, where d is the gcd of a and b normalized as in [`gcd`].
+/// Polynomial gcds are left as computed, not made monic.
+pub fn gcdext<E:EuclideanDomain>(a:&E,b:&E)->[E;3]{
+    let (mut u,mut v,mut d)=(a.one(),a.zero(),a.clone());
+    let (mut u1,mut v1,mut d1)=(a.zero(),a.one(),b.clone());
+    while !d1.is_zero() {
+        let (q,r)=d.div_rem(&d1);
+        let t=u-q.clone()*&u1;
+        u=u1;
+        u1=t;
+        let t=v-q*&v1;
+        v=v1;
+        v1=t;
+        d=d1;
+        d1=r;
+    }
+    let unit=d.normalizing_unit();
+    [unit.clone()*&u,unit.clone()*&v,unit*&d]
+}
+/// Generalized Chinese remainder theorem: given pairs (xi,mi) with non-zero moduli, returns (x,m)
+/// with m=lcm(m1,...,mk), normalized as in [`gcd`], and x = xi mod mi for every i, reduced modulo m:
+/// for the integers m>0 and 0<=x<m. The moduli need not be coprime, the output is None if the
+/// congruences are inconsistent or the input is empty.
+///
+/// # Example
+/// ```
+/// use algebra::ring::chinese_remainder;
+/// use num_bigint::BigInt;
+/// // x = 1 mod 3 and x = 0 mod -5: the truncated remainder of -5 would be negative
+/// let pairs=[(BigInt::from(1),BigInt::from(3)),(BigInt::from(0),BigInt::from(-5))];
+/// assert_eq!(chinese_remainder(&pairs),Some((BigInt::from(10),BigInt::from(15))));
+/// ```
+pub fn chinese_remainder<E:EuclideanDomain>(residues:&[(E,E)])->Option<(E,E)>{
+    let (x,m)=residues.first()?;
+    let m=m.normalizing_unit()*m;
+    let mut x=x.div_rem(&m).1;
+    let mut m=m;
+    for (residue,n) in residues.iter().skip(1) {
+        // u*m+v*n=d, hence m*u = d mod n
+        let [u,_,d]=gcdext(&m, n);
+        let (quotient,remainder)=(residue.clone()-x.clone()).div_rem(&d);
+        if !remainder.is_zero() {return None;}
+        let lcm=m.div_rem(&d).0*n;
+        let lcm=lcm.normalizing_unit()*&lcm;
+        x=(x+m*u*quotient).div_rem(&lcm).1;
+        m=lcm;
+    }
+    Some((x,m))
+}
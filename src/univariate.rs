@@ -1,6 +1,8 @@
 #[macro_use]
 pub mod poly;
 pub mod polymod;
+pub mod galois;
+pub mod gf2n;
 
 #[cfg(test)]
 mod tests {
@@ -9,7 +11,12 @@ use std::ops::Neg;
 use num_bigint::BigInt;
 use crate::{intmod::PrimeField, univariate::poly::Poly};
 
-use super::polymod::{Modulus, PolyMod};
+use super::polymod::{Modulus, PolyMod, PolyGarner};
+use super::galois::{GaloisField, GFElement};
+use super::gf2n::BinaryField;
+use crate::field::Field;
+use crate::integers::Factorization;
+use num_traits::{One, ToPrimitive};
 #[test]
 fn test_add_poly() {
 let z13=PrimeField(BigInt::from(13));
@@ -76,5 +83,262 @@ fn test_chinese_univariate() {
 
 
 
+}
+#[test]
+fn test_chinese_univariate_non_coprime() {
+    let z13=PrimeField(BigInt::from(13));
+    // (x+1)(x+2) and (x+1)(x+3) share the factor x+1
+    let x_1=poly!(z13.one(), z13.one());
+    let m1=&x_1*&poly!(z13.one(), z13.new(BigInt::from(2)));
+    let m2=&x_1*&poly!(z13.one(), z13.new(BigInt::from(3)));
+    let solution=poly!(z13.new(BigInt::from(4)), z13.new(BigInt::from(7)), z13.new(BigInt::from(1)));
+    let p1=Modulus(m1.clone()).new(solution.clone());
+    let p2=Modulus(m2.clone()).new(solution.clone());
+    let chinese=PolyMod::chinese(vec![&p1,&p2]).unwrap();
+    assert_eq!(chinese.modulus.0.deg(),3);
+    assert_eq!(Modulus(m1.clone()).new(chinese.poly.clone()),p1);
+    assert_eq!(Modulus(m2.clone()).new(chinese.poly.clone()),p2);
+    // inconsistent residues modulo x+1
+    let p3=Modulus(m2).new(&solution+&poly!(z13.one()));
+    assert_eq!(PolyMod::chinese(vec![&p1,&p3]),None);
+}
+#[test]
+fn test_poly_garner() {
+    let z13=PrimeField(BigInt::from(13));
+    let moduli=[poly!(z13.one(), z13.one(), z13.new(BigInt::from(2))),
+        poly!(z13.one(), z13.new(BigInt::from(5))),
+        poly!(z13.new(BigInt::from(3)), z13.zero(), z13.zero(), z13.one())];
+    let garner=PolyGarner::new(&moduli).unwrap();
+    let x=poly!(z13.new(BigInt::from(9)), z13.new(BigInt::from(1)), z13.new(BigInt::from(12)),
+        z13.new(BigInt::from(4)), z13.new(BigInt::from(7)), z13.zero());
+    let residues:Vec<Poly<_>>=moduli.iter().map(|m|&x%m).collect();
+    assert_eq!(garner.reconstruct(&residues).poly,x);
+    let polymods:Vec<PolyMod<_>>=moduli.iter().map(|m|Modulus(m.clone()).new(x.clone())).collect();
+    assert_eq!(PolyMod::chinese(polymods.iter().collect()).unwrap().poly,x);
+    assert!(PolyGarner::new(&[moduli[0].clone(),&moduli[0]*&moduli[1]]).is_none());
+}
+/// All the elements of a small field, as polynomials of degree below n.
+fn all_elements(field:&GaloisField)->Vec<GFElement>{
+    let p=field.characteristic().to_u64().unwrap();
+    let n=field.degree();
+    let mut elements=vec![field.zero()];
+    for k in 1..p.pow(n as u32) {
+        let coeffs:Vec<_>=(0..n).rev().map(|i|field.base_field().new(BigInt::from(k/p.pow(i as u32)%p))).collect();
+        elements.push(field.new(Poly::new_from_coeffs(&coeffs)));
+    }
+    elements
+}
+#[test]
+fn test_galois_field_brute_force() {
+    for (p,n) in [(2u64,1usize),(2,4),(3,3),(5,2),(7,1)] {
+        let base=PrimeField(BigInt::from(p));
+        let field=GaloisField::with_degree(&base, n);
+        let elements=all_elements(&field);
+        let q=field.order();
+        assert_eq!(BigInt::from(elements.len()),q);
+        let distinct:std::collections::BTreeSet<_>=elements.iter().cloned().collect();
+        assert_eq!(distinct.len(),elements.len());
+        let mut primitive=0;
+        for x in &elements {
+            // x^q = x and the Frobenius fixes exactly F_p
+            assert_eq!(&x.pow(&q),x);
+            assert_eq!(x.frobenius()==*x,x.0.poly.is_constant());
+            assert_eq!(x.norm().is_zero(),x.is_zero());
+            if !x.is_zero() {
+                assert!((x.clone()*x.inverse()).is_one());
+                assert_eq!(x.pow(&BigInt::from(-1)),x.inverse());
+                let order=x.multiplicative_order().unwrap();
+                if order==&q-1 {primitive+=1;}
+            }
+        }
+        // phi(q-1) primitive elements
+        assert_eq!(BigInt::from(primitive),Factorization::new(&(&q-1)).euler_phi());
+        // the trace is onto F_p, each value being taken q/p times
+        for t in 0..p {
+            let count=elements.iter().filter(|x|x.trace()==base.new(BigInt::from(t))).count();
+            assert_eq!(BigInt::from(count),&q/p);
+        }
+        let g=field.random_primitive_element();
+        assert_eq!(g.multiplicative_order(),Some(&q-1));
+    }
+}
+#[test]
+fn test_irreducible_count() {
+    // Gauss's formula: there are (1/n) sum_(d|n) mu(d) p^(n/d) monic irreducible polynomials of degree n
+    for (p,n,expected) in [(2u64,4usize,3usize),(2,5,6),(3,2,3),(3,3,8),(5,2,10)] {
+        let base=PrimeField(BigInt::from(p));
+        let count=(0..p.pow(n as u32)).filter(|k|{
+            let mut coeffs=vec![base.one()];
+            coeffs.extend((0..n).rev().map(|i|base.new(BigInt::from(k/p.pow(i as u32)%p))));
+            GaloisField::try_new(Poly::new_from_coeffs(&coeffs)).is_ok()
+        }).count();
+        assert_eq!(count,expected);
+    }
+}
+#[test]
+fn test_poly_over_galois_field() {
+    let f9=GaloisField::with_degree(&PrimeField(BigInt::from(3)), 2);
+    let a=f9.x();
+    // (y-a)(y-a^3) has coefficients in F_3: y^2-tr(a)y+N(a)
+    let f=&poly!(f9.one(), -a.clone())*&poly!(f9.one(), -a.frobenius());
+    let expected=poly!(f9.one(), -f9.from_base(&a.trace()), f9.from_base(&a.norm()));
+    assert_eq!(f,expected);
+    let g=&poly!(f9.one(), -a.clone())*&poly!(f9.one(), f9.one());
+    let bezout=Poly::gcdext(&f, &g);
+    assert_eq!(&(&f*&bezout[0])+&(&g*&bezout[1]),bezout[2]);
+    assert_eq!(bezout[2].deg(),1);
+    assert!(bezout[2].evaluate(&a).is_zero());
+}
+#[test]
+fn test_binary_field_against_galois_field() {
+    for field in [BinaryField::aes(),BinaryField::with_degree(13),BinaryField::with_degree(31)] {
+        let galois=field.galois_field();
+        for _ in 0..10 {
+            let (x,y)=(field.random(),field.random());
+            let (gx,gy)=(galois.new(x.to_poly()),galois.new(y.to_poly()));
+            assert_eq!(field.from_poly(&x.to_poly()),x);
+            assert_eq!((x.clone()+y.clone()).to_poly(),(gx.clone()+gy.clone()).0.poly);
+            assert_eq!((x.clone()*y.clone()).to_poly(),(gx.clone()*gy.clone()).0.poly);
+            assert_eq!(x.trace(),gx.trace().n.is_one());
+            if !x.is_zero() {assert_eq!(x.inverse().to_poly(),gx.inverse().0.poly);}
+        }
+    }
+}
+#[test]
+fn test_binary_field_large() {
+    for field in [BinaryField::with_degree(64),BinaryField::with_degree(127),BinaryField::gcm(),BinaryField::with_degree(163)] {
+        for _ in 0..20 {
+            let (x,y,z)=(field.random(),field.random(),field.random());
+            assert_eq!((x.clone()+y.clone())*z.clone(),x.clone()*z.clone()+y.clone()*z.clone());
+            assert_eq!(x.square(),x.clone()*x.clone());
+            // the trace from the precomputed mask agrees with the definition
+            let trace=(1..field.degree()).fold(x.clone(),|t,i|t+x.frobenius(i));
+            assert_eq!(trace.is_one(),x.trace());
+            assert!(trace.is_zero() || trace.is_one());
+            if !x.is_zero() {assert!((x.clone()*x.inverse()).is_one());}
+        }
+        // x^(2^n) = x
+        let x=field.random();
+        assert_eq!(x.frobenius(field.degree()),x);
+        assert_eq!(x.pow(&field.order()),x);
+    }
+}
+#[test]
+fn test_binary_field_brute_force() {
+    // every element of GF(2^8) is invertible but zero, and the x^8+x^4+x^3+x+1 has 0x03 as generator
+    let aes=BinaryField::aes();
+    let generator=aes.from_u64(3);
+    let mut power=aes.one();
+    let mut seen=std::collections::BTreeSet::new();
+    for _ in 0..255 {
+        assert!((power.clone()*power.inverse()).is_one());
+        seen.insert(power.to_u64());
+        power=power*&generator;
+    }
+    assert!(power.is_one());
+    assert_eq!(seen.len(),255);
+    assert_eq!(aes.from_u64(0x53).inverse().to_u64(),0xca);
+    // z^2+z = a is solved by the half-trace exactly when Tr(a)=0
+    let field=BinaryField::with_degree(11);
+    let mut traceless=0;
+    for n in 0..2048 {
+        let a=field.from_u64(n);
+        let z=a.half_trace();
+        assert_eq!(z.square()+z.clone()==a,!a.trace());
+        if !a.trace() {traceless+=1;}
+    }
+    assert_eq!(traceless,1024);
+}
+#[test]
+fn test_poly_over_binary_field() {
+    let aes=BinaryField::aes();
+    let a=aes.from_u64(0x57);
+    // (y+a)(y+a^2) and (y+a)(y+1) have gcd y+a
+    let f=&poly!(aes.one(), a.clone())*&poly!(aes.one(), a.square());
+    let g=&poly!(aes.one(), a.clone())*&poly!(aes.one(), aes.one());
+    let bezout=Poly::gcdext(&f, &g);
+    assert_eq!(&(&f*&bezout[0])+&(&g*&bezout[1]),bezout[2]);
+    assert_eq!(bezout[2].deg(),1);
+    assert!(bezout[2].evaluate(&a).is_zero());
+}
+#[test]
+fn test_polynomials_over_polynomials() {
+    use crate::ring::{self, Ring};
+    let z13=PrimeField(BigInt::from(13));
+    // coefficients in F_13[y]
+    let one=poly!(z13.one());
+    let y=poly!(z13.one(), z13.zero());
+    // (y*x+1)*(y*x-1) = y^2*x^2-1
+    let f=poly!(y.clone(), one.clone());
+    let g=poly!(y.clone(), -one.clone());
+    let product=&f*&g;
+    assert_eq!(product,poly!(&y*&y, Ring::zero(&y), -one.clone()));
+    // evaluating at x=y gives y^4-1 in F_13[y]
+    assert_eq!(product.evaluate(&y),poly!(z13.one(), z13.zero(), z13.zero(), z13.zero(), -z13.one()));
+    // generic extended Euclid over F_13[y] agrees with Poly::gcdext
+    let a=&(&y-&one)*&(&y+&one);
+    let b=&(&y-&one)*&(&y*&y);
+    let [u,v,d]=ring::gcdext(&a,&b);
+    assert_eq!(&(&a*&u)+&(&b*&v),d);
+    assert_eq!(vec![u,v,d.clone()],Poly::gcdext(&a,&b));
+    assert_eq!(d.deg(),1);
+    assert_eq!(ring::gcd(&a,&b),d);
+}
+#[test]
+fn test_polymod_is_ring() {
+    use crate::ring::Ring;
+    fn cube<R:Ring>(x:&R)->R{
+        x.clone()*x*x
+    }
+    let z5=PrimeField(BigInt::from(5));
+    // F_5[x]/(x^2) has the nilpotent x
+    let modulus=Modulus(poly!(z5.one(), z5.zero(), z5.zero()));
+    let x=modulus.new(poly!(z5.one(), z5.zero()));
+    assert!(Ring::is_zero(&cube(&x)));
+    let y=modulus.new(poly!(z5.new(BigInt::from(2)), z5.one()));
+    assert_eq!(cube(&y),y.pow(&BigInt::from(3)));
+    assert_eq!(cube(&z5.new(BigInt::from(2))),z5.new(BigInt::from(3)));
+}
+#[test]
+fn test_polymod_pow() {
+    // F_(2^8) as F_2[x]/(x^8+x^4+x^3+x+1), the field of AES
+    let z2=PrimeField(BigInt::from(2));
+    let bits=|n:u32|Poly::new_from_coeffs(&(0..=n.ilog2()).rev().map(|i|z2.new(BigInt::from((n>>i)&1))).collect::<Vec<_>>());
+    let modulus=Modulus(bits(0x11b));
+    let x=modulus.new(bits(0x3));
+    // 3 is a generator of the multiplicative group, of order 255
+    assert!(x.pow(&BigInt::from(255)).is_one());
+    assert!(!x.pow(&BigInt::from(85)).is_one());
+    assert!(!x.pow(&BigInt::from(51)).is_one());
+    assert!(!x.pow(&BigInt::from(15)).is_one());
+    let mut naive=x.one();
+    for e in 0..300 {
+        assert_eq!(x.pow(&BigInt::from(e)),naive);
+        assert_eq!(x.pow_ladder(&BigInt::from(e),9),naive);
+        naive=naive*&x;
+    }
+    assert_eq!(x.pow(&BigInt::from(-1)),x.inverse());
+    assert_eq!(x.pow_ladder(&BigInt::from(-7),9),x.pow(&BigInt::from(248)));
+}
+#[test]
+fn test_galois_quadratic_character() {
+    use crate::integers::QuadraticCharacter;
+    for (p,n) in [(2u64,3usize),(3,1),(3,2),(3,3),(5,2),(7,2)] {
+        let field=GaloisField::with_degree(&PrimeField(BigInt::from(p)), n);
+        let elements=all_elements(&field);
+        let q=field.order();
+        let squares:std::collections::BTreeSet<GFElement>=elements.iter().map(|x|x.clone()*x.clone()).collect();
+        let mut residues=0;
+        for x in &elements {
+            let character=x.quadratic_character();
+            assert_eq!(x.is_quadratic_residue(),!x.is_zero() && squares.contains(x));
+            if x.is_zero() {assert_eq!(character,QuadraticCharacter::Zero); continue;}
+            if character.is_residue() {residues+=1;}
+            // Euler's criterion in GF(q)
+            if p!=2 {assert_eq!(x.pow(&((&q-1)>>1)).is_one(),character.is_residue());}
+        }
+        let expected=if p==2 {&q-1} else {(&q-1)>>1};
+        assert_eq!(BigInt::from(residues),expected);
+    }
 }
 }
\ No newline at end of file
@@ -0,0 +1,339 @@
+//! Finite fields GF(p^n) = F_p[x]/(f), with f irreducible of degree n.
+//! [`GaloisField`] holds the modulus f and [`GFElement`] wraps a [`PolyMod<Mod>`] reduced modulo f,
+//! implementing [`Field`] so that `Poly<GFElement>` and other generic code work over extension fields.
+//! Irreducibility is checked with Ben-Or's test: f is irreducible if and only if gcd(f,x^(p^i)-x)=1
+//! for i=1,...,n/2 [R.Lidl H.Niederreiter Finite fields, Theorem 3.20] and
+//! [V.Shoup A computational introduction to number theory and algebra, section 20.4].
+use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use crate::field::Field;
+use crate::integers::{Factorization, QuadraticCharacter};
+use crate::intmod::{Mod, PrimeField, MathError};
+use super::poly::Poly;
+use super::polymod::{Modulus, PolyMod};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GaloisField {
+    modulus:Modulus<Mod>,
+    base:PrimeField
+}
+impl GaloisField {
+/// Checked constructor: returns F_p[x]/(f), or `MathError::ReducibleModulus` if f is not
+/// irreducible over F_p. The modulus is made monic.
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::galois::GaloisField;
+/// use algebra::univariate::poly::Poly;
+/// use algebra::intmod::{PrimeField,MathError};
+/// let z3=PrimeField(BigInt::from(3));
+/// // x^2+1 is irreducible over F_3, x^2+2 = (x+1)(x+2) is not
+/// let f9=GaloisField::try_new(Poly::new_from_coeffs(&[z3.one(), z3.zero(), z3.one()])).unwrap();
+/// assert_eq!(f9.order(),BigInt::from(9));
+/// let reducible=Poly::new_from_coeffs(&[z3.one(), z3.zero(), z3.new(BigInt::from(2))]);
+/// assert_eq!(GaloisField::try_new(reducible),Err(MathError::ReducibleModulus));
+/// ```
+    pub fn try_new(f:Poly<Mod>)->Result<GaloisField,MathError>{
+        if f.len()<2 || !is_irreducible(&f) {return Err(MathError::ReducibleModulus);}
+        Ok(GaloisField::new_unchecked(f))
+    }
+/// Builds the field without checking that f is irreducible. The modulus is made monic.
+    pub fn new_unchecked(mut f:Poly<Mod>)->GaloisField{
+        let base=f.coeffs[0].modulus.clone();
+        let leading=f.coeffs[0].inverse();
+        let f=f.multiple(&leading);
+        GaloisField { modulus: Modulus(f), base }
+    }
+/// The field GF(p^n), defined by the first monic irreducible polynomial of degree n found by
+/// enumerating the lower coefficients, constant term first.
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::galois::GaloisField;
+/// use algebra::intmod::PrimeField;
+/// let f8=GaloisField::with_degree(&PrimeField(BigInt::from(2)),3);
+/// assert_eq!(f8.modulus().0.to_string(),"Mod(1,2)*x^3+Mod(1,2)*x+Mod(1,2)");
+/// ```
+    pub fn with_degree(base:&PrimeField,n:usize)->GaloisField{
+        if n==0 {panic!("The degree of an extension must be positive")}
+        let mut k=BigInt::zero();
+        loop {
+            let mut coeffs=vec![base.zero();n+1];
+            coeffs[0]=base.one();
+            let mut digits=k.clone();
+            for i in (1..=n).rev() {
+                coeffs[i]=base.new(&digits%&base.0);
+                digits/=&base.0;
+            }
+            if !digits.is_zero() {panic!("No irreducible polynomial of degree {} over F_{}",n,base.0)}
+            let f=Poly::new_from_coeffs(&coeffs);
+            if is_irreducible(&f) {return GaloisField::new_unchecked(f);}
+            k+=1;
+        }
+    }
+    pub fn modulus(&self)->&Modulus<Mod>{
+        &self.modulus
+    }
+    pub fn base_field(&self)->&PrimeField{
+        &self.base
+    }
+    pub fn characteristic(&self)->&BigInt{
+        &self.base.0
+    }
+/// The degree n of the extension.
+    pub fn degree(&self)->usize{
+        self.modulus.0.deg()
+    }
+/// The number of elements p^n.
+    pub fn order(&self)->BigInt{
+        self.base.0.pow(self.degree() as u32)
+    }
+/// The class of the polynomial g.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(&self,g:Poly<Mod>)->GFElement{
+        GFElement(self.modulus.new(g))
+    }
+/// The image of an element of F_p.
+    pub fn from_base(&self,x:&Mod)->GFElement{
+        self.new(Poly::new_from_coeffs(std::slice::from_ref(x)))
+    }
+/// The class of x, a root of the modulus.
+    pub fn x(&self)->GFElement{
+        self.new(Poly::new_from_coeffs(&[self.base.one(),self.base.zero()]))
+    }
+    pub fn zero(&self)->GFElement{
+        GFElement(self.modulus.clone().zero())
+    }
+    pub fn one(&self)->GFElement{
+        GFElement(self.modulus.clone().one())
+    }
+    pub fn random(&self)->GFElement{
+        let coeffs:Vec<Mod>=(0..self.degree()).map(|_|self.base.random()).collect();
+        self.new(Poly::new_from_coeffs(&coeffs))
+    }
+/// Uniformly chosen generator of the multiplicative group.
+    pub fn random_primitive_element(&self)->GFElement{
+        let group_order=Factorization::new(&(self.order()-BigInt::one()));
+        loop {
+            let g=self.random();
+            if g.multiplicative_order_with(&group_order)==Some(group_order.value()) {return g;}
+        }
+    }
+}
+/// Ben-Or's irreducibility test over F_p.
+fn is_irreducible(f:&Poly<Mod>)->bool{
+    let n=f.deg();
+    if n==0 {return false;}
+    let p=f.coeffs[0].modulus.0.clone();
+    let modulus=Modulus(f.clone());
+    let x=modulus.new(Poly::new_from_coeffs(&[f.coeffs[0].one(),f.coeffs[0].zero()]));
+    let mut power=x.clone();
+    for _ in 0..n/2 {
+        // power = x^(p^i) mod f
+        power=power.pow(&p);
+        let difference=(power.clone()-&x).poly;
+        let gcd=Poly::gcdext(f, &difference)[2].clone();
+        if !gcd.is_constant() {return false;}
+    }
+    true
+}
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GFElement(pub PolyMod<Mod>);
+impl GFElement {
+    pub fn field(&self)->GaloisField{
+        GaloisField { modulus: self.0.modulus.clone(), base: self.0.poly.coeffs[0].modulus.clone() }
+    }
+/// Computes self^exp, negative exponents are allowed for non-zero elements.
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::galois::GaloisField;
+/// use algebra::intmod::PrimeField;
+/// use algebra::field::Field;
+/// let f49=GaloisField::with_degree(&PrimeField(BigInt::from(7)),2);
+/// let x=f49.random();
+/// if !x.is_zero() {assert!(x.pow(&BigInt::from(48)).is_one());}
+/// assert_eq!(x.pow(&BigInt::from(49)),x);
+/// ```
+    pub fn pow(&self,exp:&BigInt)->GFElement{
+        GFElement(self.0.pow(exp))
+    }
+/// The Frobenius automorphism x -> x^p.
+    pub fn frobenius(&self)->GFElement{
+        self.pow(&self.0.poly.coeffs[0].modulus.0)
+    }
+/// The trace over F_p, x + x^p + ... + x^(p^(n-1)).
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::galois::GaloisField;
+/// use algebra::intmod::PrimeField;
+/// let z3=PrimeField(BigInt::from(3));
+/// let f27=GaloisField::with_degree(&z3,3);
+/// let (x,y)=(f27.random(),f27.random());
+/// assert_eq!((x.clone()+y.clone()).trace(),&x.trace()+&y.trace());
+/// // the trace of an element of F_3 is 3 times itself
+/// assert_eq!(f27.one().trace(),z3.zero());
+/// ```
+    pub fn trace(&self)->Mod{
+        let mut conjugate=self.clone();
+        let mut trace=self.clone();
+        for _ in 1..self.0.modulus.0.deg() {
+            conjugate=conjugate.frobenius();
+            trace=trace+&conjugate;
+        }
+        into_base(&trace)
+    }
+/// The norm over F_p, x * x^p * ... * x^(p^(n-1)) = x^((p^n-1)/(p-1)).
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::galois::GaloisField;
+/// use algebra::intmod::PrimeField;
+/// let f25=GaloisField::with_degree(&PrimeField(BigInt::from(5)),2);
+/// let (x,y)=(f25.random(),f25.random());
+/// assert_eq!((x.clone()*y.clone()).norm(),&x.norm()*&y.norm());
+/// ```
+    pub fn norm(&self)->Mod{
+        let field=self.field();
+        let p=field.characteristic();
+        into_base(&self.pow(&((field.order()-BigInt::one())/(p-BigInt::one()))))
+    }
+/// The quadratic character of GF(q), q=p^n odd: x^((q-1)/2) = N(x)^((p-1)/2), so x is a square if and
+/// only if its norm is a square in F_p, which only needs a Legendre symbol [R.Lidl H.Niederreiter
+/// Finite fields, section 2.3]. Every non-zero element is a residue in characteristic 2.
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::galois::GaloisField;
+/// use algebra::intmod::PrimeField;
+/// use algebra::integers::QuadraticCharacter;
+/// // the elements of F_3 are squares in F_9
+/// let f9=GaloisField::with_degree(&PrimeField(BigInt::from(3)),2);
+/// let minus_one=f9.from_base(&PrimeField(BigInt::from(3)).new(BigInt::from(2)));
+/// assert_eq!(minus_one.quadratic_character(),QuadraticCharacter::Residue);
+/// assert_eq!((f9.x()*f9.x()).quadratic_character(),QuadraticCharacter::Residue);
+/// assert_eq!(f9.zero().quadratic_character(),QuadraticCharacter::Zero);
+/// ```
+    pub fn quadratic_character(&self)->QuadraticCharacter{
+        if self.is_zero() {return QuadraticCharacter::Zero;}
+        if self.field().characteristic()==&BigInt::from(2) {return QuadraticCharacter::Residue;}
+        self.norm().quadratic_character()
+    }
+/// Whether the element is a non-zero square.
+    pub fn is_quadratic_residue(&self)->bool{
+        self.quadratic_character().is_residue()
+    }
+/// The multiplicative order, or None for zero.
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::galois::GaloisField;
+/// use algebra::intmod::PrimeField;
+/// // x is a primitive element of F_8 = F_2[x]/(x^3+x+1)
+/// let f8=GaloisField::with_degree(&PrimeField(BigInt::from(2)),3);
+/// assert_eq!(f8.x().multiplicative_order(),Some(BigInt::from(7)));
+/// assert_eq!(f8.zero().multiplicative_order(),None);
+/// ```
+    pub fn multiplicative_order(&self)->Option<BigInt>{
+        self.multiplicative_order_with(&Factorization::new(&(self.field().order()-BigInt::one())))
+    }
+/// Same as [`GFElement::multiplicative_order`], with the factorization of p^n-1 given by the caller.
+    pub fn multiplicative_order_with(&self,group_order:&Factorization)->Option<BigInt>{
+        if self.is_zero() {return None;}
+        let mut order=group_order.value();
+        for (q,e) in &group_order.0 {
+            for _ in 0..*e {
+                let candidate=&order/q;
+                if self.pow(&candidate).is_one() {order=candidate;} else {break;}
+            }
+        }
+        Some(order)
+    }
+}
+/// The constant term of an element of the prime subfield.
+fn into_base(x:&GFElement)->Mod{
+    if !x.0.poly.is_constant() {panic!("{} is not in the prime field",x)}
+    x.0.poly.coeffs[0].clone()
+}
+impl fmt::Display for GFElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({})", self.0.poly.print_poly("a"))
+    }
+}
+impl Add<GFElement> for GFElement {
+    type Output = GFElement;
+    fn add(self, rhs: Self) -> Self::Output {
+        GFElement(self.0+rhs.0)
+    }
+}
+impl<'a> Add<&'a GFElement> for GFElement {
+    type Output = GFElement;
+    fn add(self, rhs: &'a GFElement) -> Self::Output {
+        GFElement(self.0+&rhs.0)
+    }
+}
+impl<'a> Add<&'a mut GFElement> for GFElement {
+    type Output = GFElement;
+    fn add(self, rhs: &'a mut GFElement) -> Self::Output {
+        GFElement(self.0+&rhs.0)
+    }
+}
+impl Sub<GFElement> for GFElement {
+    type Output = GFElement;
+    fn sub(self, rhs: Self) -> Self::Output {
+        GFElement(self.0-rhs.0)
+    }
+}
+impl Mul<GFElement> for GFElement {
+    type Output = GFElement;
+    fn mul(self, rhs: Self) -> Self::Output {
+        GFElement(self.0*rhs.0)
+    }
+}
+impl<'a> Mul<&'a GFElement> for GFElement {
+    type Output = GFElement;
+    fn mul(self, rhs: &'a GFElement) -> Self::Output {
+        GFElement(self.0*&rhs.0)
+    }
+}
+impl Div<GFElement> for GFElement {
+    type Output = GFElement;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self*rhs.inverse()
+    }
+}
+impl Neg for GFElement {
+    type Output = GFElement;
+    fn neg(self) -> Self::Output {
+        GFElement(-self.0)
+    }
+}
+impl Field for GFElement {
+    fn one(&self)->Self {
+        GFElement(self.0.one())
+    }
+    fn zero(&self)->Self {
+        GFElement(self.0.zero())
+    }
+    fn is_zero(&self)->bool {
+        self.0.is_zero()
+    }
+    fn is_one(&self)->bool {
+        self.0.is_one()
+    }
+    fn inverse(&self)->Self {
+        if self.is_zero() {panic!("Not an invertible element!")}
+        let modulus=&self.0.modulus;
+        GFElement(modulus.new(self.0.inverse().poly))
+    }
+}
@@ -0,0 +1,457 @@
+//! Binary fields GF(2^n) = F_2[x]/(f) in bit-packed form.
+//! An element is the vector of its n coefficients packed in `u64` words, lowest degree first, so that
+//! addition is a word-wise xor. Products are computed with a carry-less 64x64-bit multiplication
+//! (4-bit windows) and reduced by a sparse modulus, a trinomial or a pentanomial, by folding the
+//! high part back with x^n = x^k1 + ... + 1. Inversion uses the Itoh–Tsujii addition chain,
+//! a^-1 = (a^(2^(n-1)-1))^2, which needs about n squarings and log(n) multiplications.
+//! See [D.Hankerson A.Menezes S.Vanstone Guide to elliptic curve cryptography, sections 2.3 and 3.5] and
+//! [T.Itoh S.Tsujii A fast algorithm for computing multiplicative inverses in GF(2^m) using normal bases, 1988].
+use std::{ops::{Add, Mul, Neg, Sub, Div}, fmt, cmp::Ordering, sync::Arc};
+use num_bigint::BigInt;
+use num_traits::One;
+use rand::Rng;
+use crate::field::Field;
+use crate::intmod::{Mod, PrimeField, MathError};
+use super::poly::Poly;
+use super::galois::GaloisField;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct BinaryFieldParams {
+    degree:usize,
+    /// exponents of the lower terms of the modulus, decreasing, the last one being 0
+    exponents:Vec<usize>,
+    /// number of words of an element
+    words:usize,
+    /// bit i is the trace of x^i
+    trace_mask:Vec<u64>
+}
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BinaryField(Arc<BinaryFieldParams>);
+impl BinaryField {
+/// Checked constructor: returns F_2[x]/(x^n + x^k1 + ... + 1), where exponents lists k1,...,0, or
+/// `MathError::ReducibleModulus` if the modulus is not irreducible. Irreducibility is tested with
+/// Ben-Or's test, as in [`GaloisField::try_new`].
+///
+/// # Example
+/// ```
+/// use algebra::univariate::gf2n::BinaryField;
+/// use algebra::intmod::MathError;
+/// // the AES field, x^8+x^4+x^3+x+1
+/// let aes=BinaryField::try_new(8,&[4,3,1,0]).unwrap();
+/// let x=aes.from_u64(0x57);
+/// let y=aes.from_u64(0x83);
+/// assert_eq!((x*y).to_u64(),0xc1);
+/// // x^8+x^4+1 = (x^4+x^2+1)^2
+/// assert_eq!(BinaryField::try_new(8,&[4,0]),Err(MathError::ReducibleModulus));
+/// ```
+    pub fn try_new(n:usize,exponents:&[usize])->Result<BinaryField,MathError>{
+        let field=BinaryField::new_unchecked(n, exponents);
+        if field.is_irreducible() {Ok(field)} else {Err(MathError::ReducibleModulus)}
+    }
+/// Builds the field without checking that the modulus is irreducible. It panics if the exponents
+/// are not decreasing, below n, and ending with 0.
+    pub fn new_unchecked(n:usize,exponents:&[usize])->BinaryField{
+        if n==0 {panic!("The degree of an extension must be positive")}
+        if exponents.last()!=Some(&0) || exponents.windows(2).any(|w|w[0]<=w[1]) || exponents[0]>=n {
+            panic!("{:?} are not the decreasing lower exponents of a modulus of degree {}",exponents,n)
+        }
+        // Tr(x^i) for i<n, the trace being F_2-linear. The power sums s_i = Tr(x^i) of the roots of
+        // f = x^n + c_(n-1) x^(n-1) + ... + c_0 satisfy Newton's identities, which over F_2 read
+        // s_i = i c_(n-i) + c_(n-1) s_(i-1) + ... + c_(n-i+1) s_1, with s_0 = n.
+        let mut sums=vec![n%2==1];
+        for i in 1..n {
+            let mut s=i%2==1 && exponents.contains(&(n-i));
+            for k in exponents.iter().filter(|k|**k>n-i) {s^=sums[i-(n-k)];}
+            sums.push(s);
+        }
+        let mut trace_mask=vec![0u64;n.div_ceil(64)];
+        for (i,s) in sums.iter().enumerate() {
+            if *s {trace_mask[i/64]|=1<<(i%64);}
+        }
+        let params=BinaryFieldParams { degree: n, exponents: exponents.to_vec(), words: n.div_ceil(64), trace_mask };
+        BinaryField(Arc::new(params))
+    }
+/// The field GF(2^n) defined by the irreducible trinomial x^n+x^k+1 with the least k, or the
+/// irreducible pentanomial x^n+x^k1+x^k2+x^k3+1 with the least (k1,k2,k3) if there are none,
+/// following the convention of [IEEE 1363-2000, Annex A.8].
+///
+/// # Example
+/// ```
+/// use algebra::univariate::gf2n::BinaryField;
+/// assert_eq!(BinaryField::with_degree(8).exponents(),&[4,3,1,0]);
+/// assert_eq!(BinaryField::with_degree(233).exponents(),&[74,0]);
+/// ```
+    pub fn with_degree(n:usize)->BinaryField{
+        if n==1 {return BinaryField::new_unchecked(1, &[0]);}
+        for k in 1..n {
+            if let Ok(field)=BinaryField::try_new(n, &[k,0]) {return field;}
+        }
+        for k1 in 3..n {
+            for k2 in 2..k1 {
+                for k3 in 1..k2 {
+                    if let Ok(field)=BinaryField::try_new(n, &[k1,k2,k3,0]) {return field;}
+                }
+            }
+        }
+        panic!("No irreducible trinomial or pentanomial of degree {}",n)
+    }
+/// GF(2^8) with the modulus x^8+x^4+x^3+x+1 of AES.
+    pub fn aes()->BinaryField{
+        BinaryField::new_unchecked(8, &[4,3,1,0])
+    }
+/// GF(2^128) with the modulus x^128+x^7+x^2+x+1 of GCM. GCM itself stores the coefficients
+/// in reflected bit order, which this type does not do.
+    pub fn gcm()->BinaryField{
+        BinaryField::new_unchecked(128, &[7,2,1,0])
+    }
+    pub fn degree(&self)->usize{
+        self.0.degree
+    }
+/// The exponents k1,...,0 of the lower terms of the modulus.
+    pub fn exponents(&self)->&[usize]{
+        &self.0.exponents
+    }
+/// The number of elements 2^n.
+    pub fn order(&self)->BigInt{
+        BigInt::one()<<self.0.degree
+    }
+/// The modulus as a polynomial over F_2.
+    pub fn modulus(&self)->Poly<Mod>{
+        let mut words=vec![0u64;(self.0.degree+1).div_ceil(64)];
+        words[self.0.degree/64]|=1<<(self.0.degree%64);
+        for k in &self.0.exponents {words[k/64]|=1<<(k%64);}
+        words_to_poly(&words, self.0.degree+1)
+    }
+/// The same field as a [`GaloisField`] over [`PrimeField`] 2.
+    pub fn galois_field(&self)->GaloisField{
+        GaloisField::new_unchecked(self.modulus())
+    }
+/// The element with the given coefficients, bit i of the words being the coefficient of x^i.
+    pub fn from_words(&self,words:&[u64])->GF2n{
+        GF2n { words: self.reduce(words.to_vec()), field: self.clone() }
+    }
+/// The element with coefficients given by the bits of n.
+    pub fn from_u64(&self,n:u64)->GF2n{
+        self.from_words(&[n])
+    }
+/// The class of a polynomial over F_2.
+    pub fn from_poly(&self,g:&Poly<Mod>)->GF2n{
+        if g.coeffs[0].modulus!=PrimeField(BigInt::from(2)) {panic!("{} is not a polynomial over F_2",g)}
+        self.from_words(&poly_to_words(g))
+    }
+    pub fn zero(&self)->GF2n{
+        GF2n { words: vec![0;self.0.words], field: self.clone() }
+    }
+    pub fn one(&self)->GF2n{
+        self.from_u64(1)
+    }
+/// The class of x, a root of the modulus.
+    pub fn x(&self)->GF2n{
+        self.from_u64(2)
+    }
+    pub fn random(&self)->GF2n{
+        let mut rng=rand::thread_rng();
+        let words:Vec<u64>=(0..self.0.words).map(|_|rng.gen()).collect();
+        GF2n { words: truncate(words, self.0.degree), field: self.clone() }
+    }
+/// Reduces a packed polynomial modulo x^n + x^k1 + ... + 1, folding the part above x^n until
+/// the degree is below n. Each fold lowers the degree by n-k1.
+    fn reduce(&self,mut t:Vec<u64>)->Vec<u64>{
+        let n=self.0.degree;
+        while degree(&t).is_some_and(|d|d>=n) {
+            let high=shift_right(&t, n);
+            t=truncate(t, n);
+            for k in &self.0.exponents {xor_shifted(&mut t, &high, *k);}
+        }
+        t.resize(self.0.words, 0);
+        t
+    }
+/// Ben-Or's test: the modulus f is irreducible if and only if gcd(f,x^(2^i)-x)=1 for i=1,...,n/2.
+    fn is_irreducible(&self)->bool{
+        let modulus=poly_to_words(&self.modulus());
+        let x=self.x();
+        let mut power=x.clone();
+        for _ in 0..self.0.degree/2 {
+            power=power.square();
+            let difference=(power.clone()+&x).words;
+            if degree(&words_gcd(modulus.clone(), difference))!=Some(0) {return false;}
+        }
+        true
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GF2n {
+    words:Vec<u64>,
+    field:BinaryField
+}
+impl GF2n {
+    pub fn field(&self)->&BinaryField{
+        &self.field
+    }
+/// The coefficients, bit i being the coefficient of x^i.
+    pub fn words(&self)->&[u64]{
+        &self.words
+    }
+/// The coefficients of an element of a field of degree at most 64.
+    pub fn to_u64(&self)->u64{
+        if self.words.len()>1 {panic!("{} does not fit in a word",self)}
+        self.words[0]
+    }
+    pub fn to_poly(&self)->Poly<Mod>{
+        words_to_poly(&self.words, self.field.0.degree)
+    }
+    pub fn square(&self)->GF2n{
+        GF2n { words: self.field.reduce(square_words(&self.words)), field: self.field.clone() }
+    }
+/// Computes self^(2^k), the k-th power of the Frobenius automorphism.
+    pub fn frobenius(&self,k:usize)->GF2n{
+        (0..k).fold(self.clone(),|x,_|x.square())
+    }
+/// Computes self^exp by left-to-right square and multiply, negative exponents are allowed
+/// for non-zero elements.
+    pub fn pow(&self,exp:&BigInt)->GF2n{
+        let base=if exp<&BigInt::from(0) {self.inverse()} else {self.clone()};
+        let exp=if exp<&BigInt::from(0) {-exp} else {exp.clone()};
+        let mut result=self.field.one();
+        for i in (0..exp.bits()).rev() {
+            result=result.square();
+            if exp.bit(i) {result=result*&base;}
+        }
+        result
+    }
+/// The trace over F_2, x + x^2 + ... + x^(2^(n-1)), read off the precomputed traces of the monomials.
+///
+/// # Example
+/// ```
+/// use algebra::univariate::gf2n::BinaryField;
+/// let field=BinaryField::gcm();
+/// let (x,y)=(field.random(),field.random());
+/// assert_eq!((x.clone()+y.clone()).trace(),x.trace()^y.trace());
+/// assert_eq!(x.square().trace(),x.trace());
+/// ```
+    pub fn trace(&self)->bool{
+        let ones:u32=self.words.iter().zip(&self.field.0.trace_mask).map(|(w,m)|(w&m).count_ones()).sum();
+        ones%2==1
+    }
+/// The half-trace x + x^(2^2) + x^(2^4) + ... + x^(2^(n-1)), for odd n. When the trace of a is zero,
+/// z = H(a) solves z^2 + z = a [Hankerson, Menezes, Vanstone, section 3.6.2].
+///
+/// # Example
+/// ```
+/// use algebra::univariate::gf2n::BinaryField;
+/// let field=BinaryField::with_degree(163);
+/// let a=field.random();
+/// let z=a.half_trace();
+/// if !a.trace() {assert_eq!(z.square()+z,a);}
+/// ```
+    pub fn half_trace(&self)->GF2n{
+        let n=self.field.0.degree;
+        if n.is_multiple_of(2) {panic!("The half-trace is only defined in odd degree, found {}",n)}
+        let mut term=self.clone();
+        let mut half_trace=self.clone();
+        for _ in 0..(n-1)/2 {
+            term=term.frobenius(2);
+            half_trace=half_trace+&term;
+        }
+        half_trace
+    }
+    fn check_field(&self,other:&GF2n){
+        if self.field!=other.field {panic!("Elements of different fields!")}
+    }
+}
+/// Carry-less product of two words, as (low,high), with a 4-bit window on b.
+fn clmul64(a:u64,b:u64)->(u64,u64){
+    let mut table=[0u128;16];
+    for i in 1..16 {
+        table[i]=if i%2==0 {table[i/2]<<1} else {table[i-1]^a as u128};
+    }
+    let mut product=0u128;
+    for i in (0..16).rev() {
+        product=(product<<4)^table[((b>>(4*i))&15) as usize];
+    }
+    (product as u64,(product>>64) as u64)
+}
+fn mul_words(a:&[u64],b:&[u64])->Vec<u64>{
+    let mut product=vec![0u64;a.len()+b.len()];
+    for (i,x) in a.iter().enumerate() {
+        if *x==0 {continue;}
+        for (j,y) in b.iter().enumerate() {
+            let (low,high)=clmul64(*x, *y);
+            product[i+j]^=low;
+            product[i+j+1]^=high;
+        }
+    }
+    product
+}
+/// Squaring is linear over F_2: the bits are spread out with zeros in between.
+fn square_words(a:&[u64])->Vec<u64>{
+    let spread=|half:u64|->u64{
+        let mut x=half&0xffff_ffff;
+        x=(x|(x<<16))&0x0000_ffff_0000_ffff;
+        x=(x|(x<<8))&0x00ff_00ff_00ff_00ff;
+        x=(x|(x<<4))&0x0f0f_0f0f_0f0f_0f0f;
+        x=(x|(x<<2))&0x3333_3333_3333_3333;
+        (x|(x<<1))&0x5555_5555_5555_5555
+    };
+    a.iter().flat_map(|w|[spread(*w),spread(w>>32)]).collect()
+}
+fn degree(words:&[u64])->Option<usize>{
+    words.iter().enumerate().rev().find(|(_,w)|**w!=0).map(|(i,w)|64*i+63-w.leading_zeros() as usize)
+}
+/// The polynomial t divided by x^shift, without remainder.
+fn shift_right(t:&[u64],shift:usize)->Vec<u64>{
+    let (words,bits)=(shift/64,shift%64);
+    (words..t.len()).map(|i|{
+        let low=t[i]>>bits;
+        let high=if bits==0 {0} else {t.get(i+1).map_or(0,|w|w<<(64-bits))};
+        low|high
+    }).collect()
+}
+/// The polynomial t modulo x^n.
+fn truncate(mut t:Vec<u64>,n:usize)->Vec<u64>{
+    t.truncate(n.div_ceil(64));
+    if !n.is_multiple_of(64) {
+        if let Some(last)=t.get_mut(n/64) {*last&=(1<<(n%64))-1;}
+    }
+    t
+}
+/// t += h*x^shift
+fn xor_shifted(t:&mut Vec<u64>,h:&[u64],shift:usize){
+    let (words,bits)=(shift/64,shift%64);
+    if t.len()<h.len()+words+1 {t.resize(h.len()+words+1, 0);}
+    for (i,w) in h.iter().enumerate() {
+        t[i+words]^=w<<bits;
+        if bits!=0 {t[i+words+1]^=w>>(64-bits);}
+    }
+}
+/// Euclid's algorithm on packed polynomials.
+fn words_gcd(mut a:Vec<u64>,mut b:Vec<u64>)->Vec<u64>{
+    while let Some(db)=degree(&b) {
+        // a mod b by long division
+        while let Some(da)=degree(&a).filter(|da|*da>=db) {
+            xor_shifted(&mut a, &b, da-db);
+        }
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
+fn words_to_poly(words:&[u64],len:usize)->Poly<Mod>{
+    let f2=PrimeField(BigInt::from(2));
+    let coeffs:Vec<Mod>=(0..len).rev().map(|i|f2.new(BigInt::from((words[i/64]>>(i%64))&1))).collect();
+    Poly::new_from_coeffs(&coeffs)
+}
+fn poly_to_words(g:&Poly<Mod>)->Vec<u64>{
+    let mut words=vec![0u64;g.len().div_ceil(64)];
+    for (i,c) in g.coeffs.iter().rev().enumerate() {
+        if c.n.is_one() {words[i/64]|=1<<(i%64);}
+    }
+    words
+}
+impl fmt::Display for GF2n {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x")?;
+        let digits=self.field.0.degree.div_ceil(4);
+        for i in (0..digits).rev() {
+            write!(f, "{:x}", (self.words[i/16]>>(4*(i%16)))&15)?;
+        }
+        Ok(())
+    }
+}
+impl PartialOrd for GF2n {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// Elements are ordered as the integers with the same bits.
+impl Ord for GF2n {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.field,self.words.iter().rev().collect::<Vec<_>>()).cmp(&(&other.field,other.words.iter().rev().collect::<Vec<_>>()))
+    }
+}
+impl<'a> Add<&'a GF2n> for GF2n {
+    type Output = GF2n;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(mut self, rhs: &'a GF2n) -> Self::Output {
+        self.check_field(rhs);
+        for (x,y) in self.words.iter_mut().zip(&rhs.words) {*x^=y;}
+        self
+    }
+}
+impl Add<GF2n> for GF2n {
+    type Output = GF2n;
+    fn add(self, rhs: Self) -> Self::Output {
+        self+&rhs
+    }
+}
+impl<'a> Add<&'a mut GF2n> for GF2n {
+    type Output = GF2n;
+    fn add(self, rhs: &'a mut GF2n) -> Self::Output {
+        self+&*rhs
+    }
+}
+/// In characteristic 2 subtraction is addition.
+impl Sub<GF2n> for GF2n {
+    type Output = GF2n;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self+&rhs
+    }
+}
+impl<'a> Mul<&'a GF2n> for GF2n {
+    type Output = GF2n;
+    fn mul(self, rhs: &'a GF2n) -> Self::Output {
+        self.check_field(rhs);
+        GF2n { words: self.field.reduce(mul_words(&self.words, &rhs.words)), field: self.field }
+    }
+}
+impl Mul<GF2n> for GF2n {
+    type Output = GF2n;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self*&rhs
+    }
+}
+impl Div<GF2n> for GF2n {
+    type Output = GF2n;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self*&rhs.inverse()
+    }
+}
+impl Neg for GF2n {
+    type Output = GF2n;
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+impl Field for GF2n {
+    fn one(&self)->Self {
+        self.field.one()
+    }
+    fn zero(&self)->Self {
+        self.field.zero()
+    }
+    fn is_zero(&self)->bool {
+        self.words.iter().all(|w|*w==0)
+    }
+    fn is_one(&self)->bool {
+        self.words[0]==1 && self.words[1..].iter().all(|w|*w==0)
+    }
+/// Itoh–Tsujii inversion: with b_k = a^(2^k-1), b_(2k) = b_k^(2^k) b_k and b_(k+1) = b_k^2 a,
+/// following the binary expansion of n-1, and a^-1 = b_(n-1)^2.
+    fn inverse(&self)->Self {
+        if self.is_zero() {panic!("Not an invertible element!")}
+        let m=self.field.0.degree-1;
+        if m==0 {return self.clone();}
+        let mut b=self.clone();
+        let mut k=1;
+        for i in (0..usize::BITS-1-m.leading_zeros()).rev() {
+            b=b.frobenius(k)*&b;
+            k*=2;
+            if (m>>i)&1==1 {
+                b=b.square()*self;
+                k+=1;
+            }
+        }
+        b.square()
+    }
+}
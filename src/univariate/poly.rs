@@ -3,11 +3,13 @@ use std::{ops::{Add, Sub, Mul, Div, Rem, Neg}, cmp::{max, min}};
 use num_traits::{Zero, One};
 
 use crate::field::Field;
+use crate::ring::{self, Ring};
+use crate::error::AlgebraError;
 #[derive(Debug, PartialEq, Clone, Eq,PartialOrd,Ord)]
 pub struct Poly<T> {
     pub coeffs: Vec<T>,
 }
-impl <F:Field>fmt::Display for Poly<F> {
+impl <R:Ring>fmt::Display for Poly<R> {
     // This trait requires `fmt` with this exact signature.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         
@@ -34,8 +36,8 @@ macro_rules! poly {
     };
 }
 
-impl <F:Field> Poly <F> {
-    pub fn new_from_coeffs(coeffs:&[F])->Self {
+impl <R:Ring> Poly <R> {
+    pub fn new_from_coeffs(coeffs:&[R])->Self {
         let new_coeffs=Poly::remove_zeros(&mut coeffs.to_vec());
         Poly { coeffs: new_coeffs } 
     }
@@ -49,7 +51,7 @@ impl <F:Field> Poly <F> {
     pub fn is_constant(&self)->bool {
         self.len().is_one()
     }
-    pub fn remove_zeros( vec:&mut Vec<F>)->Vec<F>{
+    pub fn remove_zeros( vec:&mut Vec<R>)->Vec<R>{
         loop {
             if vec.len().is_one(){break vec.to_vec();}
             if vec.first().is_some() && vec.first().unwrap().is_zero() {
@@ -57,7 +59,7 @@ impl <F:Field> Poly <F> {
         /* while vec.last().is_some() && vec.last().unwrap().is_zero() {
             vec.remove(vec.len()-1);}*/        
     }
-    pub fn extend(&self,zero_to_add:usize)->Vec<F> {
+    pub fn extend(&self,zero_to_add:usize)->Vec<R> {
         let zero=self.coeffs[0].zero();
         let mut new_coeffs=vec![zero;self.len()+zero_to_add];
             for i in 0..self.len() {
@@ -66,11 +68,11 @@ impl <F:Field> Poly <F> {
         new_coeffs
         
     } 
-    pub fn one(&self)->Poly<F>{
+    pub fn one(&self)->Poly<R>{
         let mut coeff=[self.coeffs[0].one()];
         Poly::new_from_coeffs(&mut coeff)
     }
-    pub fn zero(&self)->Poly<F> {
+    pub fn zero(&self)->Poly<R> {
         let mut coeff=[self.coeffs[0].zero()];
         Poly::new_from_coeffs(&mut coeff)
     } 
@@ -81,14 +83,20 @@ impl <F:Field> Poly <F> {
         self==&self.one()
     }
 }
-impl <F:Field>Add<Poly<F>>for  Poly<F> {
-    type Output = Poly<F>;
-    fn add(self, rhs: Poly<F>) -> Self::Output {
+impl <R:Ring>Add<Poly<R>>for  Poly<R> {
+    type Output = Poly<R>;
+    fn add(self, rhs: Poly<R>) -> Self::Output {
         &self+&rhs
     }
 }
-impl <'a,'b,F:Field>Add<&'b Poly<F>>for  &'b Poly<F> {
-    type Output=Poly<F>;
+impl <'b,R:Ring>Add<&'b Poly<R>>for  Poly<R> {
+    type Output = Poly<R>;
+    fn add(self, rhs: &'b Poly<R>) -> Self::Output {
+        &self+rhs
+    }
+}
+impl <'b,R:Ring>Add<&'b Poly<R>>for  &'b Poly<R> {
+    type Output=Poly<R>;
 /// # Example
 /// ```
 /// #[macro_use] extern crate algebra;
@@ -133,7 +141,7 @@ impl <'a,'b,F:Field>Add<&'b Poly<F>>for  &'b Poly<F> {
 Poly::new_from_coeffs(&mut sum)
     }
 }
-impl <F:Field> Neg for Poly<F> {
+impl <R:Ring> Neg for Poly<R> {
     type Output = Self;
     fn neg(mut self) -> Self::Output {
         if self.is_zero(){return self.clone();}
@@ -145,8 +153,14 @@ impl <F:Field> Neg for Poly<F> {
     }
     
 }
-impl <'a,'b,F:Field>Sub<&'b Poly<F>>for  &'b Poly<F> {
-    type Output=Poly<F>;
+impl <R:Ring>Sub<Poly<R>>for  Poly<R> {
+    type Output = Poly<R>;
+    fn sub(self, rhs: Poly<R>) -> Self::Output {
+        &self-&rhs
+    }
+}
+impl <'b,R:Ring>Sub<&'b Poly<R>>for  &'b Poly<R> {
+    type Output=Poly<R>;
     fn sub(self, rhs: Self) -> Self::Output {
         let zero=self.coeffs[0].clone().zero();
         if rhs.is_zero(){return self.clone();}
@@ -166,17 +180,23 @@ impl <'a,'b,F:Field>Sub<&'b Poly<F>>for  &'b Poly<F> {
 Poly::new_from_coeffs(&mut sub)
     }
 }
-impl <F:Field> Mul<Poly<F>> for Poly<F>{
-    type Output=Poly<F>;
-    fn mul(self, rhs: Poly<F>) -> Self::Output {
+impl <R:Ring> Mul<Poly<R>> for Poly<R>{
+    type Output=Poly<R>;
+    fn mul(self, rhs: Poly<R>) -> Self::Output {
         &self*&rhs
     }
     
     
 
 }
-impl <'a,'b,F:Field>Mul<&'b Poly<F>>for  &'b Poly<F>{
-    type Output=Poly<F>;
+impl <'b,R:Ring> Mul<&'b Poly<R>> for Poly<R>{
+    type Output=Poly<R>;
+    fn mul(self, rhs: &'b Poly<R>) -> Self::Output {
+        &self*rhs
+    }
+}
+impl <'b,R:Ring>Mul<&'b Poly<R>>for  &'b Poly<R>{
+    type Output=Poly<R>;
     fn mul(self, rhs: Self) -> Self::Output {
         if self.is_zero()||rhs.is_zero(){return rhs.zero()}
         if self.is_one(){return rhs.clone();}
@@ -204,7 +224,7 @@ impl <'a,'b,F:Field + std::fmt::Debug>Rem<&'b Poly<F>>for  &'b Poly<F>{
         Poly::div_rem(self, rhs)[1].clone()
 }
 }
-impl <F:Field>Poly<F> {
+impl <R:Ring>Poly<R> {
     pub fn print_poly(&self,x:&str)->String{
         let zero=self.coeffs[0].clone().zero();
         let mut s: Vec<String> = Vec::new();
@@ -212,18 +232,40 @@ impl <F:Field>Poly<F> {
             if n.is_zero(){continue;}
             let term = if i==self.len()-1{n.to_string()} else if i==self.len()-2 {format!("{}*{}", n, x)}
             else{format!("{}*{}^{}", n, x,self.len()-i-1)};
-            if !s.is_empty()&&(*n)>zero{
+            if !s.is_empty()&&!term.starts_with('-'){
                 s.push("+".to_string());
             }
             s.push(term);
         }
+        if s.is_empty() {return zero.to_string();}
         s.concat()
     }
     
 }
 impl <F:Field> Poly<F>{
     pub fn div_rem(g:&Poly<F>,h:&Poly<F>)->Vec<Poly<F>>{
-        if g.is_zero(){return [g.clone(),h.clone()].to_vec()}
+        Poly::try_div_rem(g, h).unwrap_or_else(|error|panic!("{}",error))
+    }
+/// Quotient and remainder [q,r] of g divided by h, or `AlgebraError::DivisionByZero` if h is zero.
+///
+/// # Example
+/// ```
+/// #[macro_use] extern crate algebra;
+/// use num_bigint::BigInt;
+/// use algebra::univariate::poly::Poly;
+/// use algebra::intmod::PrimeField;
+/// use algebra::error::AlgebraError;
+/// use algebra::field::Field;
+/// let z13=PrimeField(BigInt::from(13));
+/// let g=poly!(z13.one(), z13.zero(), z13.one());
+/// let h=poly!(z13.one(), z13.one());
+/// let qr=Poly::try_div_rem(&g,&h).unwrap();
+/// assert_eq!(&(&qr[0]*&h)+&qr[1],g);
+/// assert_eq!(Poly::try_div_rem(&g,&h.zero()),Err(AlgebraError::DivisionByZero));
+/// ```
+    pub fn try_div_rem(g:&Poly<F>,h:&Poly<F>)->Result<Vec<Poly<F>>,AlgebraError>{
+        if h.is_zero(){return Err(AlgebraError::DivisionByZero)}
+        if g.is_zero(){return Ok([g.clone(),g.clone()].to_vec())}
             let zero=g.coeffs[0].clone().zero();
             let mut rem=g.clone();
             let mut q=g.zero();
@@ -237,11 +279,11 @@ impl <F:Field> Poly<F>{
             q=&q+&s;
             rem=&rem-&(&s*&h);
         }
-        [q,rem].to_vec()
+        Ok([q,rem].to_vec())
 
 }
 }
-impl <F:Field> Poly<F>{
+impl <R:Ring> Poly<R>{
 /// # Example
 /// ```
 /// use num_bigint::BigInt;
@@ -256,24 +298,26 @@ impl <F:Field> Poly<F>{
 /// assert_eq!(value,p1.evaluate(&alpha));
 /// ```
 /// 
-pub fn evaluate(&self,alpha:&F)->F {
+pub fn evaluate(&self,alpha:&R)->R {
     let mut value = self.coeffs[0].zero();
     for i in 0..self.len(){
         value=value*alpha.clone()+self.coeffs[i].clone();
     }
     value
 }
+pub fn multiple(&mut self, alpha:&R)->Poly<R>{
+    for i in 0..self.len(){
+    self.coeffs[i]=self.coeffs[i].clone()*alpha;}
+    Poly::new_from_coeffs(&self.coeffs)
+}
+}
+impl <F:Field> Poly<F>{
 pub fn normal_poly(&mut self)->Poly<F> {
     let c=self.coeffs.last().unwrap().inverse();
     for i in 0..self.len(){self.coeffs[i]=self.coeffs[i].clone()*&c}
     Poly::new_from_coeffs(&mut self.coeffs)
     
 }
-pub fn multiple(&mut self, alpha:&F)->Poly<F>{
-    for i in 0..self.len(){
-    self.coeffs[i]=self.coeffs[i].clone()*alpha;}
-    Poly::new_from_coeffs(&mut self.coeffs)
-}
 /// # Example
 /// This function compute Bezout identity for two polynomials p1 and p2. The output is a Vec<Pol<F>>
 /// [u,v,d], where p1*u+p2*v=d. In particular d is the gcd. Remember that the gcd
@@ -294,18 +338,7 @@ pub fn multiple(&mut self, alpha:&F)->Poly<F>{
 /// ```
 /// 
 pub fn gcdext(g:&Poly<F>,h:&Poly<F>)->Vec<Poly<F>>{
-    let mut u = g.one(); let mut d=g.clone();
-    let mut v1=h.zero(); let mut v3=h.clone();
-    while !v3.is_zero() {
-        let div_rem= Poly::div_rem(&d,&v3);
-        let t= &u-&(&v1*&div_rem[0]);
-        u=v1;
-        d=v3;
-        v1=t;
-        v3=div_rem[1].clone();
-    }
-    let v=&(&d-&(g*&u))/h;
-    [u,v,d].to_vec()
+    ring::gcdext(g, h).to_vec()
     }
 pub fn is_coprime(g:&Poly<F>,h:&Poly<F>)->bool{
     Poly::gcdext(g, h)[2].is_one()
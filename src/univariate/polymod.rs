@@ -1,6 +1,11 @@
 use core::fmt;
 use std::ops::{Add, Sub,Neg,Mul};
+use num_bigint::BigInt;
+use num_traits::Zero;
 use crate::field::Field;
+use crate::ring::chinese_remainder;
+use crate::error::AlgebraError;
+use crate::exponentiation::{pow_sliding_window, pow_ladder};
 
 use super::poly::Poly;
 #[derive(Debug,PartialEq,Eq, PartialOrd, Ord,Clone)]
@@ -42,11 +47,76 @@ impl<F:Field>PolyMod<F>{
         Self::new(Poly::one(&self.poly), self.modulus.clone())
     }
     pub fn inverse(&self)->Self{
+        self.try_inverse().unwrap_or_else(|error|panic!("{}",error))
+    }
+/// Inverse modulo the modulus, or `AlgebraError::NonInvertiblePolynomial` if the two are not coprime.
+///
+/// # Example
+/// ```
+/// #[macro_use] extern crate algebra;
+/// use num_bigint::BigInt;
+/// use algebra::univariate::polymod::Modulus;
+/// use algebra::intmod::PrimeField;
+/// use algebra::error::AlgebraError;
+/// use algebra::field::Field;
+/// let z5=PrimeField(BigInt::from(5));
+/// // x^2-1 = (x-1)(x+1)
+/// let modulus=Modulus(poly!(z5.one(), z5.zero(), -z5.one()));
+/// let x=modulus.new(poly!(z5.one(), z5.zero()));
+/// assert!((x.clone()*x.try_inverse().unwrap()).is_one());
+/// let y=modulus.new(poly!(z5.one(), z5.one()));
+/// assert_eq!(y.try_inverse(),Err(AlgebraError::NonInvertiblePolynomial));
+/// ```
+    pub fn try_inverse(&self)->Result<Self,AlgebraError>{
         let bez=Poly::gcdext(&self.poly, &self.modulus.0);
-        println!("bez[2] is {}",bez[2]);
-        if !bez[2].is_constant() {panic!("{} is not invertible mod {}",self.poly,self.modulus.0);}
-        PolyMod::new(bez[0].clone().multiple(&bez[2].coeffs[0].inverse()),self.modulus.clone())
+        if !bez[2].is_constant() || bez[2].is_zero() {return Err(AlgebraError::NonInvertiblePolynomial);}
+        Ok(PolyMod::new(bez[0].clone().multiple(&bez[2].coeffs[0].inverse()),self.modulus.clone()))
+    }
+/// Sum of two elements, or `AlgebraError::ModulusMismatch` if their moduli differ.
+    pub fn checked_add(&self,rhs:&Self)->Result<Self,AlgebraError>{
+        if self.modulus!=rhs.modulus {return Err(AlgebraError::ModulusMismatch);}
+        // both degrees are below the degree of the modulus, and so is the degree of the sum
+        Ok(PolyMod::new(&self.poly+&rhs.poly, self.modulus.clone()))
+    }
+/// Difference of two elements, or `AlgebraError::ModulusMismatch` if their moduli differ.
+    pub fn checked_sub(&self,rhs:&Self)->Result<Self,AlgebraError>{
+        if self.modulus!=rhs.modulus {return Err(AlgebraError::ModulusMismatch);}
+        Ok(PolyMod::new(&self.poly-&rhs.poly, self.modulus.clone()))
+    }
+/// Product of two elements, or `AlgebraError::ModulusMismatch` if their moduli differ.
+    pub fn checked_mul(&self,rhs:&Self)->Result<Self,AlgebraError>{
+        if self.modulus!=rhs.modulus {return Err(AlgebraError::ModulusMismatch);}
+        Ok(PolyMod::new(&(&self.poly*&rhs.poly)%&self.modulus.0, self.modulus.clone()))
+    }
+/// Computes self^exp with [`pow_sliding_window`], negative exponents are allowed
+/// for invertible elements.
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::polymod::Modulus;
+/// use algebra::univariate::poly::Poly;
+/// use algebra::intmod::PrimeField;
+/// use algebra::field::Field;
+/// let z3=PrimeField(BigInt::from(3));
+/// // F_9 = F_3[x]/(x^2+1), whose multiplicative group has order 8
+/// let modulus=Modulus(Poly::new_from_coeffs(&[z3.one(), z3.zero(), z3.one()]));
+/// let x=modulus.new(Poly::new_from_coeffs(&[z3.one(), z3.zero()]));
+/// assert!(x.pow(&BigInt::from(4)).is_one());
+/// assert_eq!(x.pow(&BigInt::from(-1)),x.pow(&BigInt::from(3)));
+/// ```
+    pub fn pow(&self,exp:&BigInt)->Self{
+        if exp<&BigInt::zero() {return pow_sliding_window(&self.inverse(), &-exp);}
+        pow_sliding_window(self, exp)
     }
+/// Computes self^exp with the Montgomery ladder of [`pow_ladder`], over exactly `bits` bits of
+/// the exponent, which must fit. Negative exponents are allowed for invertible elements.
+    pub fn pow_ladder(&self,exp:&BigInt,bits:u64)->Self{
+        if exp<&BigInt::zero() {return pow_ladder(&self.inverse(), &-exp, bits);}
+        pow_ladder(self, exp, bits)
+    }
+/// Generalized Chinese Remainder Algorithm: returns the class modulo lcm(m1,...,mk) congruent to
+/// each input. The moduli need not be coprime, the output is None only if the congruences are inconsistent.
 /// # Example
 /// ```
 /// #[macro_use] extern crate algebra;
@@ -70,18 +140,78 @@ impl<F:Field>PolyMod<F>{
 /// 
 /// ```
     pub fn chinese(moduli:Vec<&PolyMod<F>>)->Option<Self> {
-        let mut m=moduli[0].modulus.0.clone();
-        let mut x=moduli[0].poly.clone();
-        
-        for i in 1..moduli.len() {
-            let uvd=Poly::gcdext(&moduli[i].modulus.0, &m);
-            if uvd[2]!=x.one() {return None;} 
-            x=(&uvd[0]*&moduli[i].modulus.0)*x+&(&uvd[1]*&moduli[i].poly)*&m;
-            m=&m*&moduli[i].modulus.0;
-            x=&x%&m;               
+        let pairs:Vec<(Poly<F>,Poly<F>)>=moduli.iter().map(|x|(x.poly.clone(),x.modulus.0.clone())).collect();
+        let (x,m)=chinese_remainder(&pairs)?;
+        Some(PolyMod::new(x, Modulus(m)))
+    }
+}
+/// Garner's algorithm for polynomials: the analogue of [`crate::intmod::modn::Garner`] for a fixed
+/// list of pairwise coprime moduli m1,...,mk in F[x]. The constants (m1...m(i-1))^-1 mod mi are
+/// computed once, and each reconstruction goes through the mixed-radix form
+/// x = v1 + v2*m1 + v3*m1*m2 + ... with deg(vi)<deg(mi).
+///
+/// # Example
+/// ```
+/// use num_bigint::BigInt;
+/// use algebra::univariate::polymod::{PolyGarner,PolyMod};
+/// use algebra::univariate::poly::Poly;
+/// use algebra::intmod::PrimeField;
+/// use algebra::field::Field;
+/// let z13=PrimeField(BigInt::from(13));
+/// let m1=Poly::new_from_coeffs(&[z13.one(), z13.one(), z13.one()]);
+/// let m2=Poly::new_from_coeffs(&[z13.one(), z13.new(BigInt::from(3))]);
+/// let garner=PolyGarner::new(&[m1.clone(),m2.clone()]).unwrap();
+/// let residues=[Poly::new_from_coeffs(&[z13.one(), z13.one()]),Poly::new_from_coeffs(&[z13.new(BigInt::from(5))])];
+/// let x=garner.reconstruct(&residues);
+/// assert_eq!(&x.poly%&m1,residues[0]);
+/// assert_eq!(&x.poly%&m2,residues[1]);
+/// ```
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct PolyGarner<F> {
+    moduli:Vec<Poly<F>>,
+    constants:Vec<Poly<F>>,
+    modulus:Modulus<F>
+}
+impl<F:Field> PolyGarner<F> {
+/// Precomputes the constants for the given moduli, or returns None if they are not pairwise coprime.
+    pub fn new(moduli:&[Poly<F>])->Option<Self>{
+        let mut product=moduli.first()?.clone();
+        let mut constants=vec![product.one()];
+        for m in moduli.iter().skip(1) {
+            let uvd=Poly::gcdext(&product, m);
+            if !uvd[2].is_constant() || uvd[2].is_zero() {return None;}
+            let mut inverse=&uvd[0]%m;
+            constants.push(inverse.multiple(&uvd[2].coeffs[0].inverse()));
+            product=&product*m;
         }
-            Some(PolyMod::new(x, Modulus(m)))
-
+        Some(PolyGarner { moduli: moduli.to_vec(), constants, modulus: Modulus(product) })
+    }
+/// The modulus m1...mk of the reconstructed elements.
+    pub fn modulus(&self)->&Modulus<F>{
+        &self.modulus
+    }
+/// Mixed-radix digits v1,...,vk of the element with the given residues, with deg(vi)<deg(mi).
+    pub fn mixed_radix(&self,residues:&[Poly<F>])->Vec<Poly<F>>{
+        if residues.len()!=self.moduli.len() {panic!("Expected {} residues, found {}",self.moduli.len(),residues.len())}
+        let mut digits:Vec<Poly<F>>=Vec::with_capacity(residues.len());
+        for (i,(r,m)) in residues.iter().zip(&self.moduli).enumerate() {
+            // value of v1 + v2*m1 + ... + vi*m1...m(i-1) modulo mi, by Horner's rule
+            let mut partial=m.zero();
+            for j in (0..i).rev() {
+                partial=&(&(&partial*&self.moduli[j])+&digits[j])%m;
+            }
+            digits.push(&(&(r-&partial)*&self.constants[i])%m);
+        }
+        digits
+    }
+/// The element of F[x]/(m1...mk) with the given residues.
+    pub fn reconstruct(&self,residues:&[Poly<F>])->PolyMod<F>{
+        let digits=self.mixed_radix(residues);
+        let mut x=self.modulus.0.zero();
+        for (v,m) in digits.iter().zip(&self.moduli).rev() {
+            x=&(&x*m)+v;
+        }
+        PolyMod::new(x, self.modulus.clone())
     }
 }
 impl<F:Field> fmt::Display for PolyMod<F> {
@@ -93,75 +223,44 @@ impl<F:Field> fmt::Display for PolyMod<F> {
 }
 impl<F:Field> Add<PolyMod<F>> for PolyMod<F> {
     type Output = Self;
-    
     fn add(self, rhs: Self) -> Self::Output {
-        if self.modulus==rhs.modulus{
-                let modulus=&self.modulus.0;
-                let mut sum = &self.poly+&rhs.poly;
-                if sum>=*modulus{
-                    sum = &sum-&modulus;
-                    PolyMod::new(sum, self.modulus.clone())}
-                    else {PolyMod::new(sum, self.modulus)}}
-                else {panic!("cannot add different modulus")}
-}
+        self.checked_add(&rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
 }
-impl<'a,'b,F:Field> Add<&'b PolyMod<F>> for PolyMod<F>{
+impl<'b,F:Field> Add<&'b PolyMod<F>> for PolyMod<F>{
     type Output = Self;
     fn add(self, rhs: &'b PolyMod<F>) -> Self::Output {
-        if self.modulus==rhs.modulus{
-                let modulus=&self.modulus.0;
-                let mut sum = &self.poly+&rhs.poly;
-                if sum>=*modulus{
-                    sum = &sum-&modulus;
-                    PolyMod::new(sum, self.modulus.clone())}
-                    else {PolyMod::new(sum, self.modulus)}}
-                else {panic!("cannot add different modulus")}
-}
+        self.checked_add(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
 }
 impl <F:Field> Neg for PolyMod<F>{
     type Output = Self;
     fn neg(self) -> Self::Output {
 
-            PolyMod::new(&self.modulus.0-&self.poly,self.modulus.clone())
+            PolyMod::new(-self.poly,self.modulus)
     }
 }
 impl<F:Field> Sub<PolyMod<F>> for PolyMod<F> {
     type Output = Self;    
     fn sub(self, rhs: PolyMod<F>) -> Self::Output {
-        if self.modulus==rhs.modulus{
-            PolyMod::new(&self.poly-&rhs.poly,self.modulus)
-        }
-            else {panic!("cannot add different modulus")}
-}
+        self.checked_sub(&rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
 }
-impl<'a,'b,F:Field> Sub<&'b PolyMod<F>> for PolyMod<F> {
+impl<'b,F:Field> Sub<&'b PolyMod<F>> for PolyMod<F> {
     type Output = Self;    
     fn sub(self, rhs: &'b PolyMod<F>) -> Self::Output {
-        if self.modulus==rhs.modulus{
-            PolyMod::new(&self.poly-&rhs.poly,self.modulus)
-        }
-            else {panic!("cannot add different modulus")}
-}
+        self.checked_sub(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
 }
 impl<F:Field> Mul<PolyMod<F>> for PolyMod<F> {
     type Output = Self;    
     fn mul(self, rhs: Self) -> Self::Output {
-        if self.modulus==rhs.modulus{
-            let mut poly=&self.poly*&rhs.poly;
-            poly=&poly%&self.modulus.0;
-            PolyMod::new(poly,self.modulus)
-        }
-            else {panic!("cannot add different modulus")}
-}
+        self.checked_mul(&rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
 }
-impl<'a,'b,F:Field> Mul<&'b PolyMod<F>> for PolyMod<F> {
+impl<'b,F:Field> Mul<&'b PolyMod<F>> for PolyMod<F> {
     type Output = Self;    
     fn mul(self, rhs: &'b Self) -> Self::Output {
-        if self.modulus==rhs.modulus{
-            let mut poly=&self.poly*&rhs.poly;
-            poly=&poly%&self.modulus.0;
-            PolyMod::new(poly,self.modulus)
-        }
-            else {panic!("cannot add different modulus")}
+        self.checked_mul(rhs).unwrap_or_else(|error|panic!("{}",error))
+    }
 }
-}
\ No newline at end of file